@@ -1,3 +1,4 @@
+use serde_json::{Value, from_str};
 use std::env;
 use std::error::Error;
 
@@ -6,23 +7,101 @@ use std::io::BufWriter;
 use std::io::Write;
 use std::path::Path;
 
+use crate::data::enabled;
+use crate::languages::Languages;
+
 mod codegen;
 mod countries;
+mod currencies;
+#[path = "../src/currency_kind.rs"]
+mod currency_kind;
+mod data;
+mod historic;
+mod info;
+#[path = "../src/language_kind.rs"]
+mod language_kind;
+mod languages;
 mod macros;
+#[path = "../src/region.rs"]
+mod region;
+mod scripts;
+mod sovereignty;
+mod subdivisions;
 mod time;
+mod validate;
 
 fn main() -> Result<(), Box<dyn Error>> {
     // Whole dir, not just this file: the JSON data files are codegen inputs too.
     println!("cargo:rerun-if-changed=build");
+    // Shared with the library, for the lists of known regions and currency and language kinds
+    println!("cargo:rerun-if-changed=src/region.rs");
+    println!("cargo:rerun-if-changed=src/currency_kind.rs");
+    println!("cargo:rerun-if-changed=src/language_kind.rs");
 
     let path = Path::new(&env::var_os("OUT_DIR").unwrap()).join("codegen.rs");
     let mut file = BufWriter::new(File::create(&path).unwrap());
 
+    let mut diagnostics = validate::Diagnostics::from_env();
+    // Read once, for the country tables and for data_info()
+    let countries_data = data::read_data("ISO_RS_COUNTRIES_PATH", "countries.json")?;
+    let countries: Value = from_str(&countries_data)?;
+    let timezones: Value = from_str(&data::read_data("ISO_RS_TIMEZONES_PATH", "timezones.json")?)?;
+    // The iso-codes data is LGPL, so only read for the opt-in features using it
+    let mut currencies = currencies::get_currencies(enabled("ISO4217"), &mut diagnostics)?;
+    let scripts = match enabled("ISO15924") {
+        true => Some(scripts::get_scripts(&mut diagnostics)?),
+        false => None,
+    };
+    let languages = match enabled("ISO639") {
+        true => languages::get_languages(scripts.as_ref(), &mut diagnostics)?,
+        false => Languages::new(),
+    };
+    file.write_all(
+        countries::get_countries(
+            &countries,
+            time::get_time(&timezones),
+            sovereignty::get_sovereigns()?,
+            codegen::Fields::from_env(),
+            &mut currencies,
+            &languages,
+            &mut diagnostics,
+        )?
+        .to_string()
+        .as_bytes(),
+    )?;
+
+    file.write_all(
+        currencies::currencies_tokens(&currencies)
+            .to_string()
+            .as_bytes(),
+    )?;
+    file.write_all(
+        languages::languages_tokens(&languages)
+            .to_string()
+            .as_bytes(),
+    )?;
+    if let Some(scripts) = &scripts {
+        file.write_all(scripts::scripts_tokens(scripts).to_string().as_bytes())?;
+    }
+    if enabled("SUBDIVISIONS") {
+        file.write_all(
+            subdivisions::get_subdivisions(&mut diagnostics)?
+                .to_string()
+                .as_bytes(),
+        )?;
+    }
+    if enabled("HISTORIC") {
+        file.write_all(
+            historic::get_historic(&mut diagnostics)?
+                .to_string()
+                .as_bytes(),
+        )?;
+    }
     file.write_all(
-        countries::get_countries(time::get_time()?)?
+        info::get_info(&countries_data, &countries, &timezones)?
             .to_string()
             .as_bytes(),
     )?;
 
-    Ok(())
+    diagnostics.finish()
 }
@@ -10,6 +10,7 @@ pub struct MapBuilder<'a> {
     pub subregion: Map<'a, &'a str>,
     pub alpha_2: Map<'a, &'a str>,
     pub alpha_3: Map<'a, &'a str>,
+    pub ioc_code: Map<'a, &'a str>,
 }
 
 pub struct ParsedMap {
@@ -19,6 +20,7 @@ pub struct ParsedMap {
     pub subregion: TokenStream,
     pub alpha_2: TokenStream,
     pub alpha_3: TokenStream,
+    pub ioc_code: TokenStream,
 }
 
 impl<'a> MapBuilder<'a> {
@@ -30,6 +32,7 @@ impl<'a> MapBuilder<'a> {
             subregion: Map::new(),
             alpha_2: Map::new(),
             alpha_3: Map::new(),
+            ioc_code: Map::new(),
         }
     }
 
@@ -40,6 +43,7 @@ impl<'a> MapBuilder<'a> {
     map_method!(subregion);
     map_method!(alpha_2);
     map_method!(alpha_3);
+    map_method!(ioc_code);
 
     pub fn parse(self) -> Result<ParsedMap, LexError> {
         Ok(ParsedMap {
@@ -49,6 +53,7 @@ impl<'a> MapBuilder<'a> {
             subregion: tokens!(self.subregion),
             alpha_2: tokens!(self.alpha_2),
             alpha_3: tokens!(self.alpha_3),
+            ioc_code: tokens!(self.ioc_code),
         })
     }
 }
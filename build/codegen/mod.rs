@@ -1,106 +1,259 @@
-use crate::countries::country_data::CountryData;
-use crate::field_entry;
-use serde_json::Value;
-use std::fmt::Display;
+use crate::countries::country_data::{CountryData, CurrencyData, LanguageData, TimezoneData};
+use crate::historic::historic_data::HistoricData;
+use crate::scripts::script_data::ScriptData;
+use crate::subdivisions::subdivision_data::SubdivisionData;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use std::env;
 
 pub mod map_builder;
 
-pub fn country_struct(country_data: &CountryData) -> String {
-    let mut struct_string = String::from("Country {");
-    let mut struct_values = String::new();
+/// Which of the optional `Country` fields to generate, one per field feature.
+/// A field that is turned off is left out of every literal, data and all.
+#[derive(Debug, Clone, Copy)]
+pub struct Fields {
+    pub timezones: bool,
+    pub currencies: bool,
+    pub languages: bool,
+    pub call_codes: bool,
+    pub numeric_codes: bool,
+    pub alt_spellings: bool,
+    pub sovereignty: bool,
+    pub flags: bool,
+    pub ioc_codes: bool,
+    pub gini: bool,
+}
+
+impl Fields {
+    // Cargo tells build scripts the crate's enabled features through the environment
+    pub fn from_env() -> Self {
+        let feature = |name: &str| env::var_os(format!("CARGO_FEATURE_{name}")).is_some();
+        Self {
+            timezones: feature("TIMEZONES"),
+            currencies: feature("CURRENCIES"),
+            languages: feature("LANGUAGES"),
+            call_codes: feature("CALL_CODES"),
+            numeric_codes: feature("NUMERIC_CODES"),
+            alt_spellings: feature("ALT_SPELLINGS"),
+            sovereignty: feature("SOVEREIGNTY"),
+            flags: feature("FLAGS"),
+            ioc_codes: feature("IOC_CODES"),
+            gini: feature("GINI"),
+        }
+    }
+}
+
+// Every string goes through `Literal::string`, which escapes it as needed, so any
+// valid Unicode string round-trips into the generated source exactly.
+
+pub fn country_struct(country_data: &CountryData, fields: &Fields) -> TokenStream {
     let name = &country_data.name;
-    let capital = &country_data.capital;
-    let region = &country_data.region;
-    let subregion = &country_data.subregion;
+    let capital = option_tokens(&country_data.capital);
+    let region = option_tokens(&country_data.region);
+    let subregion = option_tokens(&country_data.subregion);
     let alpha_2 = &country_data.alpha_2;
     let alpha_3 = &country_data.alpha_3;
-    let timezones = vec_to_string(&country_data.timezones);
-    let currencies = vec_to_string(&country_data.currencies);
-    let languages = vec_to_string(&country_data.languages);
-    let call_codes = vec_to_string(&country_data.call_codes);
-    field_entry!(struct_values, name);
-    field_entry!(struct_values, Some(capital));
-    field_entry!(struct_values, Some(region));
-    field_entry!(struct_values, Some(subregion));
-    field_entry!(struct_values, alpha_2);
-    field_entry!(struct_values, alpha_3);
-    field_entry!(struct_values, timezones);
-    field_entry!(struct_values, currencies);
-    field_entry!(struct_values, languages);
-    field_entry!(struct_values, call_codes);
-    struct_string.push_str(&struct_values);
-    struct_string.push('}');
-    struct_string
+    let timezones = fields.timezones.then(|| {
+        let timezones = country_data.timezones.iter().map(timezone_struct);
+        quote!(timezones: &[#(#timezones),*],)
+    });
+    let currencies = fields.currencies.then(|| {
+        let currencies = country_data.currencies.iter().map(currency_struct);
+        quote!(currencies: &[#(#currencies),*],)
+    });
+    let languages = fields.languages.then(|| {
+        let languages = country_data.languages.iter().map(language_struct);
+        quote!(languages: &[#(#languages),*],)
+    });
+    let call_codes = fields.call_codes.then(|| {
+        let call_codes = &country_data.call_codes;
+        quote!(call_codes: &[#(#call_codes),*],)
+    });
+    let numeric_code = fields.numeric_codes.then(|| {
+        let numeric_code = option_tokens(&country_data.numeric_code);
+        quote!(numeric_code: #numeric_code,)
+    });
+    let alt_spellings = fields.alt_spellings.then(|| {
+        let alt_spellings = &country_data.alt_spellings;
+        quote!(alt_spellings: &[#(#alt_spellings),*],)
+    });
+    let sovereignty = fields.sovereignty.then(|| {
+        let independent = country_data.independent;
+        let sovereign = option_tokens(&country_data.sovereign);
+        quote!(independent: #independent, sovereign: #sovereign,)
+    });
+    let flags = fields.flags.then(|| {
+        let flag_emoji = &country_data.flag_emoji;
+        let flag_svg = option_tokens(&country_data.flag_svg);
+        let flag_png = option_tokens(&country_data.flag_png);
+        quote!(flag_emoji: #flag_emoji, flag_svg: #flag_svg, flag_png: #flag_png,)
+    });
+    let ioc_code = fields.ioc_codes.then(|| {
+        let ioc_code = option_tokens(&country_data.ioc_code);
+        quote!(ioc_code: #ioc_code,)
+    });
+    let gini = fields.gini.then(|| {
+        // Unsuffixed float literals always have a decimal point, even for whole numbers
+        let gini = option_tokens(&country_data.gini.map(Literal::f32_unsuffixed));
+        quote!(gini: #gini,)
+    });
+    quote! {
+        Country {
+            name: #name,
+            capital: #capital,
+            region: #region,
+            subregion: #subregion,
+            alpha_2: #alpha_2,
+            alpha_3: #alpha_3,
+            #timezones
+            #currencies
+            #languages
+            #call_codes
+            #numeric_code
+            #alt_spellings
+            #sovereignty
+            #flags
+            #ioc_code
+            #gini
+        }
+    }
+}
+
+pub fn option_tokens<T: quote::ToTokens>(option: &Option<T>) -> TokenStream {
+    match option {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
 }
 
-pub fn vec_to_string<T: Display>(vec: &[T]) -> String {
-    let mut vec_string = String::from("&[");
-    let mut vec_values = String::new();
-    for (i, items) in vec.iter().enumerate() {
-        vec_values.push_str(&items.to_string());
-        if i != vec.len() - 1 {
-            vec_values.push(',');
+pub fn currency_struct(currency: &CurrencyData) -> TokenStream {
+    let code = option_tokens(&currency.code);
+    let name = option_tokens(&currency.name);
+    let symbol = option_tokens(&currency.symbol);
+    let numeric_code = option_tokens(&currency.numeric_code);
+    let minor_unit = option_tokens(&currency.minor_unit);
+    let kind = option_tokens(&currency.kind.map(|kind| {
+        let kind = format_ident!("{kind:?}");
+        quote!(CurrencyKind::#kind)
+    }));
+    let introduced = option_tokens(&currency.introduced);
+    let withdrawn = option_tokens(&currency.withdrawn);
+    let replaced_by = option_tokens(&currency.replaced_by);
+    let conversion_rate = option_tokens(&currency.conversion_rate);
+    let cash_increment = option_tokens(&currency.cash_increment);
+    let narrow_symbol = option_tokens(&currency.narrow_symbol);
+    quote! {
+        Currency {
+            code: #code,
+            name: #name,
+            symbol: #symbol,
+            numeric_code: #numeric_code,
+            minor_unit: #minor_unit,
+            kind: #kind,
+            introduced: #introduced,
+            withdrawn: #withdrawn,
+            replaced_by: #replaced_by,
+            conversion_rate: #conversion_rate,
+            cash_increment: #cash_increment,
+            narrow_symbol: #narrow_symbol,
         }
     }
-    vec_string.push_str(&vec_values);
-    vec_string.push(']');
-    vec_string
 }
 
-pub fn option_to_string<T: Display>(option: &Option<T>) -> String {
-    if let Some(x) = option {
-        x.to_string()
-    } else {
-        String::from("None")
+pub fn timezone_struct(timezone: &TimezoneData) -> TokenStream {
+    let iana_identifier = &timezone.iana_identifier;
+    quote! {
+        Timezone {
+            iana_identifier: #iana_identifier,
+        }
     }
 }
 
-pub fn currency_struct(object: &Value) -> String {
-    if let Some(currency) = object.as_object() {
-        let mut struct_string = String::from("Currency {");
-        let mut struct_values = String::new();
-        field_entry!(struct_values, "name", currency);
-        field_entry!(struct_values, "code", currency);
-        field_entry!(struct_values, "symbol", currency);
-        struct_string.push_str(&struct_values);
-        struct_string.push('}');
-        struct_string
-    } else {
-        String::from("Default::default()")
+pub fn language_struct(language: &LanguageData) -> TokenStream {
+    let iso639_1 = option_tokens(&language.iso639_1);
+    let iso639_2 = option_tokens(&language.iso639_2);
+    let name = option_tokens(&language.name);
+    let native_name = option_tokens(&language.native_name);
+    let iso639_2b = option_tokens(&language.iso639_2b);
+    let iso639_3 = option_tokens(&language.iso639_3);
+    let scope = option_tokens(&language.scope.map(|scope| {
+        let scope = format_ident!("{scope:?}");
+        quote!(LanguageScope::#scope)
+    }));
+    let language_type = option_tokens(&language.language_type.map(|language_type| {
+        let language_type = format_ident!("{language_type:?}");
+        quote!(LanguageType::#language_type)
+    }));
+    let macrolanguage = option_tokens(&language.macrolanguage);
+    let script = option_tokens(&language.script);
+    quote! {
+        Language {
+            iso639_1: #iso639_1,
+            iso639_2: #iso639_2,
+            name: #name,
+            native_name: #native_name,
+            iso639_2b: #iso639_2b,
+            iso639_3: #iso639_3,
+            scope: #scope,
+            language_type: #language_type,
+            macrolanguage: #macrolanguage,
+            script: #script,
+        }
     }
 }
 
-pub fn timezone_struct(identifier: String) -> String {
-    let mut struct_string = String::from("Timezone {");
-    let mut struct_values = String::new();
-    struct_values.push_str(format!(r#"iana_identifier: {identifier},"#).as_str());
-    struct_string.push_str(&struct_values);
-    struct_string.push('}');
-    struct_string
+pub fn subdivision_struct(subdivision: &SubdivisionData) -> TokenStream {
+    let code = &subdivision.code;
+    let name = &subdivision.name;
+    let category = &subdivision.category;
+    let parent = option_tokens(&subdivision.parent);
+    quote! {
+        Subdivision {
+            code: #code,
+            name: #name,
+            category: #category,
+            parent: #parent,
+        }
+    }
 }
 
-pub fn language_struct(object: &Value) -> String {
-    if let Some(languages) = object.as_object() {
-        let mut struct_string = String::from("Language {");
-        let mut struct_values = String::new();
-        field_entry!(struct_values, "iso639_1", languages);
-        field_entry!(struct_values, "iso639_2", languages);
-        field_entry!(struct_values, "name", languages);
-        struct_values.push_str(
-            format!(
-                r#"native_name: Some({}),"#,
-                option_to_string(&languages.get("nativeName"))
-            )
-            .as_str()
-            .replace("null", "None")
-            .as_str()
-            .replace("Some(None)", "None")
-            .as_str(),
-        );
-        struct_string.push_str(&struct_values);
-        struct_string.push('}');
-        struct_string
-    } else {
-        String::from("Default::default()")
+pub fn script_struct(script: &ScriptData) -> TokenStream {
+    let alpha_4 = &script.alpha_4;
+    let numeric_code = &script.numeric_code;
+    let name = &script.name;
+    let property_value_alias = option_tokens(&script.property_value_alias);
+    quote! {
+        Script {
+            alpha_4: #alpha_4,
+            numeric_code: #numeric_code,
+            name: #name,
+            property_value_alias: #property_value_alias,
+        }
+    }
+}
+
+pub fn historic_struct(country: &HistoricData) -> TokenStream {
+    let name = &country.name;
+    let alpha_2 = &country.alpha_2;
+    let alpha_3 = &country.alpha_3;
+    let alpha_4 = &country.alpha_4;
+    let numeric_code = option_tokens(&country.numeric_code);
+    let introduced = &country.introduced;
+    let withdrawn = &country.withdrawn;
+    let comment = option_tokens(&country.comment);
+    let successors = &country.successors;
+    quote! {
+        HistoricCountry {
+            name: #name,
+            alpha_2: #alpha_2,
+            alpha_3: #alpha_3,
+            alpha_4: #alpha_4,
+            numeric_code: #numeric_code,
+            introduced: #introduced,
+            withdrawn: #withdrawn,
+            comment: #comment,
+            successors: &[#(#successors),*],
+        }
     }
 }
@@ -1,35 +1,145 @@
-#[derive(Debug)]
+// These are also the records of a `Registry` and a runtime `Dataset`, hence the docs.
+
+use crate::currency_kind::CurrencyKind;
+use crate::language_kind::{LanguageScope, LanguageType};
+
+/// An owned country record, with the same fields as
+/// [`Country`](struct.Country.html).
+#[derive(Debug, Clone, PartialEq)]
 pub struct CountryData {
+    /// Name of the country, eg. "United States"
     pub name: String,
-    pub capital: String,
-    pub region: String,
-    pub subregion: String,
+    /// Name of the country's capital, eg. "Washington, DC"
+    pub capital: Option<String>,
+    /// Region of the country, eg. "Asia"
+    pub region: Option<String>,
+    /// Subregion of the country, eg. "Southern Asia"
+    pub subregion: Option<String>,
+    /// ISO 3166-1 2-letter country code
     pub alpha_2: String,
+    /// ISO 3166-1 3-letter country code
     pub alpha_3: String,
-    pub timezones: Vec<String>,
-    pub currencies: Vec<String>,
-    pub languages: Vec<String>,
+    /// Timezones of the country
+    pub timezones: Vec<TimezoneData>,
+    /// Currencies used in the country
+    pub currencies: Vec<CurrencyData>,
+    /// Languages used in the country
+    pub languages: Vec<LanguageData>,
+    /// Dialling codes used in the country
     pub call_codes: Vec<String>,
+    /// Whether the country is a sovereign state
+    pub independent: bool,
+    /// ISO 3166-1 alpha_2 code of the sovereign state of a territory
+    pub sovereign: Option<String>,
+    /// The country's flag as an emoji
+    pub flag_emoji: String,
+    /// URL of the country's flag as an SVG image
+    pub flag_svg: Option<String>,
+    /// URL of the country's flag as a 320px wide PNG image
+    pub flag_png: Option<String>,
+    /// International Olympic Committee code, eg. "GER" for Germany
+    pub ioc_code: Option<String>,
+    /// Gini coefficient of income inequality, from 0 to 100
+    pub gini: Option<f32>,
+    /// ISO 3166-1 numeric code, eg. "356" for India
+    pub numeric_code: Option<String>,
+    /// Alternative spellings of the country's name
+    pub alt_spellings: Vec<String>,
+}
+
+/// An owned currency, like [`Currency`](struct.Currency.html)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CurrencyData {
+    /// ISO 4217 currency code
+    pub code: Option<String>,
+    /// Name of the currency in english
+    pub name: Option<String>,
+    /// Symbol of the currency
+    pub symbol: Option<String>,
+    /// ISO 4217 numeric code, eg. "978" for EUR
+    pub numeric_code: Option<String>,
+    /// Number of digits after the decimal point, eg. 0 for JPY
+    pub minor_unit: Option<u8>,
+    /// What the code stands for, `None` if it is not in ISO 4217
+    pub kind: Option<CurrencyKind>,
+    /// When the currency was introduced, if known, eg. "1999-01-01" for EUR
+    pub introduced: Option<String>,
+    /// When ISO 4217 withdrew the code, eg. "2002-03" for DEM
+    pub withdrawn: Option<String>,
+    /// Code of the currency that replaced this one, eg. "EUR" for DEM
+    pub replaced_by: Option<String>,
+    /// Units of this currency per unit of the one that replaced it, where that
+    /// was fixed, eg. "1.95583" for DEM
+    pub conversion_rate: Option<String>,
+    /// Smallest amount paid in cash, in minor units, where it isn't 1, eg. 5 for CHF
+    pub cash_increment: Option<u32>,
+    /// Symbol telling the currency apart from others sharing its symbol, eg. "US$"
+    pub narrow_symbol: Option<String>,
+}
+
+/// An owned language, like [`Language`](struct.Language.html)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LanguageData {
+    /// ISO 639-1 language code
+    pub iso639_1: Option<String>,
+    /// ISO 639-2 terminology code, eg. "fra"
+    pub iso639_2: Option<String>,
+    /// Name of the language in english
+    pub name: Option<String>,
+    /// Native name of the language
+    pub native_name: Option<String>,
+    /// ISO 639-2 bibliographic code, where it differs from the terminology one,
+    /// eg. "fre"
+    pub iso639_2b: Option<String>,
+    /// ISO 639-3 code, eg. "fra"
+    pub iso639_3: Option<String>,
+    /// Whether the code is for a single language or a macrolanguage
+    pub scope: Option<LanguageScope>,
+    /// Whether the language is living, extinct, constructed, etc.
+    pub language_type: Option<LanguageType>,
+    /// ISO 639-3 code of the macrolanguage the language belongs to, eg. "zho"
+    /// for Mandarin Chinese
+    pub macrolanguage: Option<String>,
+    /// ISO 15924 code of the script the language is usually written in, eg. "Latn"
+    pub script: Option<String>,
+}
+
+/// An owned timezone, like [`Timezone`](struct.Timezone.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimezoneData {
+    /// IANA identifier for the timezone, eg. Asia/Kolkata
+    pub iana_identifier: String,
 }
 
 impl CountryData {
-    pub fn builder() -> CountryDataBuilder {
+    /// Start building a record, with every field empty
+    pub(crate) fn builder() -> CountryDataBuilder {
         CountryDataBuilder::default()
     }
 }
 
+/// Builder for [`CountryData`](struct.CountryData.html)
 #[derive(Default, Debug)]
-pub struct CountryDataBuilder {
+pub(crate) struct CountryDataBuilder {
     name: String,
-    capital: String,
-    region: String,
-    subregion: String,
+    capital: Option<String>,
+    region: Option<String>,
+    subregion: Option<String>,
     alpha_2: String,
     alpha_3: String,
-    timezones: Vec<String>,
-    currencies: Vec<String>,
-    languages: Vec<String>,
+    timezones: Vec<TimezoneData>,
+    currencies: Vec<CurrencyData>,
+    languages: Vec<LanguageData>,
     call_codes: Vec<String>,
+    independent: bool,
+    sovereign: Option<String>,
+    flag_emoji: String,
+    flag_svg: Option<String>,
+    flag_png: Option<String>,
+    ioc_code: Option<String>,
+    gini: Option<f32>,
+    numeric_code: Option<String>,
+    alt_spellings: Vec<String>,
 }
 
 impl CountryDataBuilder {
@@ -38,17 +148,17 @@ impl CountryDataBuilder {
         self
     }
 
-    pub fn capital(mut self, capital: String) -> Self {
+    pub fn capital(mut self, capital: Option<String>) -> Self {
         self.capital = capital;
         self
     }
 
-    pub fn region(mut self, region: String) -> Self {
+    pub fn region(mut self, region: Option<String>) -> Self {
         self.region = region;
         self
     }
 
-    pub fn subregion(mut self, subregion: String) -> Self {
+    pub fn subregion(mut self, subregion: Option<String>) -> Self {
         self.subregion = subregion;
         self
     }
@@ -63,17 +173,17 @@ impl CountryDataBuilder {
         self
     }
 
-    pub fn timezones(mut self, timezones: Vec<String>) -> Self {
+    pub fn timezones(mut self, timezones: Vec<TimezoneData>) -> Self {
         self.timezones = timezones;
         self
     }
 
-    pub fn currencies(mut self, currencies: Vec<String>) -> Self {
+    pub fn currencies(mut self, currencies: Vec<CurrencyData>) -> Self {
         self.currencies = currencies;
         self
     }
 
-    pub fn languages(mut self, languages: Vec<String>) -> Self {
+    pub fn languages(mut self, languages: Vec<LanguageData>) -> Self {
         self.languages = languages;
         self
     }
@@ -83,6 +193,51 @@ impl CountryDataBuilder {
         self
     }
 
+    pub fn independent(mut self, independent: bool) -> Self {
+        self.independent = independent;
+        self
+    }
+
+    pub fn sovereign(mut self, sovereign: Option<String>) -> Self {
+        self.sovereign = sovereign;
+        self
+    }
+
+    pub fn flag_emoji(mut self, flag_emoji: String) -> Self {
+        self.flag_emoji = flag_emoji;
+        self
+    }
+
+    pub fn flag_svg(mut self, flag_svg: Option<String>) -> Self {
+        self.flag_svg = flag_svg;
+        self
+    }
+
+    pub fn flag_png(mut self, flag_png: Option<String>) -> Self {
+        self.flag_png = flag_png;
+        self
+    }
+
+    pub fn ioc_code(mut self, ioc_code: Option<String>) -> Self {
+        self.ioc_code = ioc_code;
+        self
+    }
+
+    pub fn gini(mut self, gini: Option<f32>) -> Self {
+        self.gini = gini;
+        self
+    }
+
+    pub fn numeric_code(mut self, numeric_code: Option<String>) -> Self {
+        self.numeric_code = numeric_code;
+        self
+    }
+
+    pub fn alt_spellings(mut self, alt_spellings: Vec<String>) -> Self {
+        self.alt_spellings = alt_spellings;
+        self
+    }
+
     pub fn build(self) -> CountryData {
         CountryData {
             name: self.name,
@@ -95,6 +250,25 @@ impl CountryDataBuilder {
             currencies: self.currencies,
             languages: self.languages,
             call_codes: self.call_codes,
+            independent: self.independent,
+            sovereign: self.sovereign,
+            flag_emoji: self.flag_emoji,
+            flag_svg: self.flag_svg,
+            flag_png: self.flag_png,
+            ioc_code: self.ioc_code,
+            gini: self.gini,
+            numeric_code: self.numeric_code,
+            alt_spellings: self.alt_spellings,
         }
     }
 }
+
+// Spell the alpha_2 code with regional indicator symbols, which platforms render
+// as the flag, eg. "IN" becomes "🇮🇳".
+pub fn flag_emoji(alpha_2: &str) -> String {
+    alpha_2
+        .chars()
+        .filter(char::is_ascii_uppercase)
+        .filter_map(|letter| char::from_u32(0x1F1E6 + (letter as u32 - 'A' as u32)))
+        .collect()
+}
@@ -1,79 +1,85 @@
+use crate::currencies::{Currencies, add_symbols};
+use crate::languages::{Languages, find_language};
+use crate::sovereignty::Sovereigns;
 use crate::time::Timezones;
 use crate::{
-    codegen::{
-        country_struct, currency_struct, language_struct, map_builder::MapBuilder, vec_to_string,
-    },
-    countries::country_data::CountryData,
-    hash_map_to_static,
+    codegen::{Fields, country_struct, map_builder::MapBuilder},
+    countries::{country_data::CountryData, parse::parse_countries},
     time::timezone_vec,
-    value_or_none, vec_or_none,
+    validate::{Diagnostics, UniqueCodes, validate_country},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use serde_json::{Value, from_str};
+use serde_json::Value;
 use std::collections::HashMap;
 
 use std::error::Error;
-use std::fs::File;
-use std::io::Read;
 
 pub mod country_data;
+pub mod parse;
 
-type ItemsMap<'a> = HashMap<&'a str, Vec<String>>;
+type ItemsMap<'a> = HashMap<&'a str, Vec<TokenStream>>;
 
-pub fn get_countries(timezones: Timezones) -> Result<TokenStream, Box<dyn Error>> {
-    let mut countries =
-        File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/build/countries.json")).unwrap();
-    let mut data = String::new();
-    countries.read_to_string(&mut data).unwrap();
-    let parsed: Value = from_str(data.as_str())?;
+pub fn get_countries(
+    parsed: &Value,
+    timezones: Timezones,
+    sovereigns: Sovereigns,
+    fields: Fields,
+    currencies: &mut Currencies,
+    languages: &Languages,
+    diagnostics: &mut Diagnostics,
+) -> Result<TokenStream, Box<dyn Error>> {
     let mut map = MapBuilder::new();
-    let mut vec: Vec<CountryData> = Vec::new();
+    let zones = |alpha_2: &str| {
+        timezone_vec(
+            timezones
+                .get(alpha_2)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+        )
+    };
+    let sovereign = |alpha_2: &str| sovereigns.get(alpha_2).cloned();
+    let currency = |code: &str| currencies.get(code).cloned();
+    let language = |code: &str| find_language(languages, code);
+    let mut skipped = |record: usize, name: &str, alpha_2: &str, message: &str| {
+        diagnostics.issue(record, name, alpha_2, message)
+    };
+    let (records, mut vec): (Vec<usize>, Vec<CountryData>) = parse_countries(
+        parsed,
+        &zones,
+        &sovereign,
+        &currency,
+        &language,
+        &mut skipped,
+    )
+    .into_iter()
+    .unzip();
+    // Index of the record in countries.json each country came from
+    let records: HashMap<String, usize> = vec
+        .iter()
+        .map(|data| data.name.clone())
+        .zip(records)
+        .collect();
     let mut regions: ItemsMap = HashMap::new();
     let mut subregions: ItemsMap = HashMap::new();
     let mut capitals: ItemsMap = HashMap::new();
-    let mut alpha_2: ItemsMap = HashMap::new();
-    let mut alpha_3: ItemsMap = HashMap::new();
-    for country in parsed.as_array().into_iter().flatten() {
-        let Some(country_data) = country.as_object() else {
-            continue;
-        };
-        let Some(country_name) = country_data.get("name") else {
-            continue;
-        };
-        if vec
-            .iter()
-            .any(|data| data.name.trim_matches('\"') == country_name.to_string().trim_matches('\"'))
-        {
-            continue;
-        }
-        let alpha_2 = value_or_none!("alpha2Code", country_data);
-        let zone = timezones.get(&alpha_2);
-        vec.push(
-            CountryData::builder()
-                .name(country_name.to_string())
-                .capital(value_or_none!("capital", country_data))
-                .region(value_or_none!("region", country_data))
-                .subregion(value_or_none!("subregion", country_data))
-                .alpha_2(alpha_2)
-                .alpha_3(value_or_none!("alpha3Code", country_data))
-                .timezones(timezone_vec(
-                    zone.cloned().unwrap_or_else(Vec::new).to_vec(),
-                ))
-                .currencies(vec_or_none!("currencies", country_data, currencies))
-                .languages(vec_or_none!("languages", country_data, languages))
-                .call_codes(vec_or_none!("callingCodes", country_data))
-                .build(),
-        );
-    }
+    let mut codes = UniqueCodes::new();
+
+    vec.sort_by(|a, b| a.alpha_2.cmp(&b.alpha_2));
+    add_symbols(currencies, &vec);
+    let countries = vec
+        .iter()
+        .map(|country_data| country_struct(country_data, &fields));
+    let countries = quote!(&[#(#countries),*]);
 
     for country_data in vec.iter() {
-        let country = country_struct(country_data);
+        validate_country(records[&country_data.name], country_data, diagnostics);
+        let country = country_struct(country_data, &fields);
 
-        let capital = country_data.capital.trim_matches('\"');
-        let region = country_data.region.trim_matches('\"');
-        let subregion = country_data.subregion.trim_matches('\"');
+        let capital = country_data.capital.as_deref().unwrap_or_default();
+        let region = country_data.region.as_deref().unwrap_or_default();
+        let subregion = country_data.subregion.as_deref().unwrap_or_default();
         if !capital.is_empty() {
             capitals.entry(capital).or_default().push(country.clone());
         }
@@ -89,23 +95,29 @@ pub fn get_countries(timezones: Timezones) -> Result<TokenStream, Box<dyn Error>
                 .push(country.clone());
         }
 
-        alpha_2
-            .entry(country_data.alpha_2.trim_matches('\"'))
-            .or_default()
-            .push(country.clone());
-        alpha_3
-            .entry(country_data.alpha_3.trim_matches('\"'))
-            .or_default()
-            .push(country.clone());
-        map.name()
-            .entry(country_data.name.trim_matches('\"'), country);
+        codes.add(country_data)?;
+        map.alpha_2()
+            .entry(&country_data.alpha_2, country.to_string());
+        map.alpha_3()
+            .entry(&country_data.alpha_3, country.to_string());
+        if let Some(code) = &country_data.ioc_code {
+            map.ioc_code().entry(code, country.to_string());
+        }
+        map.name().entry(&country_data.name, country.to_string());
     }
 
-    hash_map_to_static!(capitals, map, capital);
-    hash_map_to_static!(regions, map, region);
-    hash_map_to_static!(subregions, map, subregion);
-    hash_map_to_static!(alpha_2, map, alpha_2);
-    hash_map_to_static!(alpha_3, map, alpha_3);
+    for (capital, countries) in capitals {
+        map.capital()
+            .entry(capital, quote!(&[#(#countries),*]).to_string());
+    }
+    for (region, countries) in regions {
+        map.region()
+            .entry(region, quote!(&[#(#countries),*]).to_string());
+    }
+    for (subregion, countries) in subregions {
+        map.subregion()
+            .entry(subregion, quote!(&[#(#countries),*]).to_string());
+    }
 
     let parsed_map = map.parse()?;
     let names = parsed_map.name;
@@ -114,8 +126,11 @@ pub fn get_countries(timezones: Timezones) -> Result<TokenStream, Box<dyn Error>
     let subregions = parsed_map.subregion;
     let alpha_2 = parsed_map.alpha_2;
     let alpha_3 = parsed_map.alpha_3;
+    let ioc_codes = parsed_map.ioc_code;
 
     Ok(quote! {
+        /// All the countries as [`Country`](struct.Country.html), sorted by alpha_2 code.
+        pub static COUNTRIES: &[Country] = #countries;
         /// Map of all the countries with name as the key and value as [`Country`](struct.Country.html).
         pub static NAMES: phf::Map<&'static str, Country> = #names;
         #[cfg(feature = "from_capitals")]
@@ -128,10 +143,13 @@ pub fn get_countries(timezones: Timezones) -> Result<TokenStream, Box<dyn Error>
         /// Map of all subregions with countries that reside in them.
         pub static SUBREGIONS: phf::Map<&'static str, &'static [Country]> = #subregions;
         #[cfg(feature = "from_alpha_2")]
-        /// Map of all alpha_2 codes (key) with the corresponding countries as values.
-        pub static ALPHA_2: phf::Map<&'static str, &'static [Country]> = #alpha_2;
+        /// Map of all alpha_2 codes (key) with the corresponding [`Country`](struct.Country.html) as the value.
+        pub static ALPHA_2: phf::Map<&'static str, Country> = #alpha_2;
         #[cfg(feature = "from_alpha_3")]
-        /// Map of all alpha_3 codes (key) with the corresponding countries as values.
-        pub static ALPHA_3: phf::Map<&'static str, &'static [Country]> = #alpha_3;
+        /// Map of all alpha_3 codes (key) with the corresponding [`Country`](struct.Country.html) as the value.
+        pub static ALPHA_3: phf::Map<&'static str, Country> = #alpha_3;
+        #[cfg(feature = "from_ioc_codes")]
+        /// Map of all IOC codes (key) with the corresponding [`Country`](struct.Country.html) as the value.
+        pub static IOC_CODES: phf::Map<&'static str, Country> = #ioc_codes;
     })
 }
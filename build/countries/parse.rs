@@ -0,0 +1,202 @@
+// Reads the v2 restcountries schema into `CountryData`. Shared by the build
+// script and the `runtime` feature, so it only depends on serde_json.
+
+use super::country_data::{CountryData, CurrencyData, LanguageData, TimezoneData, flag_emoji};
+use serde_json::{Map, Value};
+
+use std::collections::HashMap;
+
+/// Every usable record in a countries.json array, with its index in the array,
+/// in the order they appear. Timezones and sovereign states aren't in the
+/// schema, so they come from `timezones` and `sovereign` by alpha_2 code, and
+/// each currency is completed from its ISO 4217 entry in `currency`, by code,
+/// and each language from its ISO 639-3 entry in `language`, by ISO 639-2 or
+/// ISO 639-1 code.
+/// Records that can't be used are passed to `skipped` with their index, name,
+/// alpha_2 code and the reason.
+pub fn parse_countries(
+    parsed: &Value,
+    timezones: &dyn Fn(&str) -> Vec<TimezoneData>,
+    sovereign: &dyn Fn(&str) -> Option<String>,
+    currency: &dyn Fn(&str) -> Option<CurrencyData>,
+    language: &dyn Fn(&str) -> Option<LanguageData>,
+    skipped: &mut dyn FnMut(usize, &str, &str, &str),
+) -> Vec<(usize, CountryData)> {
+    let mut countries = Vec::new();
+    let mut records: HashMap<String, usize> = HashMap::new();
+    for (record, country) in parsed.as_array().into_iter().flatten().enumerate() {
+        let Some(country_data) = country.as_object() else {
+            skipped(record, "", "", "not an object, skipped");
+            continue;
+        };
+        let alpha_2 = string_field(country_data, "alpha2Code").unwrap_or_default();
+        let Some(country_name) = string_field(country_data, "name") else {
+            skipped(record, "", &alpha_2, "no name, skipped");
+            continue;
+        };
+        // Names key the NAMES map, so a repeat can't be kept without losing a country
+        if let Some(first) = records.get(&country_name) {
+            let message = format!("name already used by countries.json[{first}], skipped");
+            skipped(record, &country_name, &alpha_2, &message);
+            continue;
+        }
+        records.insert(country_name.clone(), record);
+        let flags = country_data
+            .get("flags")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        let flag_emoji = flag_emoji(&alpha_2);
+        // Kosovo's user-assigned code is a bare number rather than a string
+        let numeric_code = match country_data.get("numericCode") {
+            Some(Value::Number(code)) => Some(format!("{code:0>3}")),
+            _ => string_field(country_data, "numericCode"),
+        };
+        // Countries without an IOC code have "" rather than null in the data
+        let ioc_code = string_field(country_data, "cioc").filter(|code| !code.is_empty());
+        let sovereign = sovereign(&alpha_2);
+        // A curated sovereign always wins; otherwise trust the data, reading null as
+        // independent since every such record so far has been a sovereign state.
+        let independent = sovereign.is_none()
+            && country_data
+                .get("independent")
+                .and_then(Value::as_bool)
+                .unwrap_or(true);
+        countries.push((
+            record,
+            CountryData::builder()
+                .name(country_name)
+                .capital(string_field(country_data, "capital"))
+                .region(string_field(country_data, "region"))
+                .subregion(string_field(country_data, "subregion"))
+                .alpha_3(string_field(country_data, "alpha3Code").unwrap_or_default())
+                .timezones(timezones(&alpha_2))
+                .alpha_2(alpha_2)
+                .currencies(
+                    objects_field(country_data, "currencies", currency_data)
+                        .into_iter()
+                        .map(|data| with_iso_4217(data, currency))
+                        .collect(),
+                )
+                .languages(
+                    objects_field(country_data, "languages", language_data)
+                        .into_iter()
+                        .map(|data| with_iso_639(data, language))
+                        .collect(),
+                )
+                .call_codes(strings_field(country_data, "callingCodes"))
+                .numeric_code(numeric_code)
+                .alt_spellings(
+                    strings_field(country_data, "altSpellings")
+                        .into_iter()
+                        .filter(|spelling| !spelling.is_empty())
+                        .collect(),
+                )
+                .independent(independent)
+                .sovereign(sovereign)
+                .flag_emoji(flag_emoji)
+                .flag_svg(string_field(&flags, "svg"))
+                .flag_png(string_field(&flags, "png"))
+                .ioc_code(ioc_code)
+                .gini(
+                    country_data
+                        .get("gini")
+                        .and_then(Value::as_f64)
+                        .map(|gini| gini as f32),
+                )
+                .build(),
+        ));
+    }
+    countries
+}
+
+// A string value, with null, a missing key and the "(none)" placeholder all
+// read as None
+fn string_field(object: &Map<String, Value>, key: &str) -> Option<String> {
+    object
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|value| *value != "(none)")
+        .map(str::to_string)
+}
+
+// The string values of an array, skipping anything that isn't a string
+fn strings_field(object: &Map<String, Value>, key: &str) -> Vec<String> {
+    object
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect()
+}
+
+// An array of objects converted one by one, with a non-object entry taking the
+// default value
+fn objects_field<T: Default>(
+    object: &Map<String, Value>,
+    key: &str,
+    convert: fn(&Map<String, Value>) -> T,
+) -> Vec<T> {
+    object
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|value| value.as_object().map(convert).unwrap_or_default())
+        .collect()
+}
+
+fn currency_data(currency: &Map<String, Value>) -> CurrencyData {
+    CurrencyData {
+        code: string_field(currency, "code"),
+        name: string_field(currency, "name"),
+        symbol: string_field(currency, "symbol"),
+        ..Default::default()
+    }
+}
+
+// Only the fields the restcountries schema lacks, its code, name and symbol are kept
+fn with_iso_4217(
+    data: CurrencyData,
+    currency: &dyn Fn(&str) -> Option<CurrencyData>,
+) -> CurrencyData {
+    let Some(iso) = data.code.as_deref().and_then(currency) else {
+        return data;
+    };
+    CurrencyData {
+        code: data.code,
+        name: data.name,
+        symbol: data.symbol,
+        ..iso
+    }
+}
+
+fn language_data(language: &Map<String, Value>) -> LanguageData {
+    LanguageData {
+        iso639_1: string_field(language, "iso639_1"),
+        iso639_2: string_field(language, "iso639_2"),
+        name: string_field(language, "name"),
+        native_name: string_field(language, "nativeName"),
+        ..Default::default()
+    }
+}
+
+// Like currencies, only the fields the restcountries schema lacks
+fn with_iso_639(
+    data: LanguageData,
+    language: &dyn Fn(&str) -> Option<LanguageData>,
+) -> LanguageData {
+    let iso = data.iso639_2.as_deref().and_then(language);
+    let Some(iso) = iso.or_else(|| data.iso639_1.as_deref().and_then(language)) else {
+        return data;
+    };
+    LanguageData {
+        iso639_1: data.iso639_1,
+        iso639_2: data.iso639_2,
+        name: data.name,
+        native_name: data.native_name,
+        ..iso
+    }
+}
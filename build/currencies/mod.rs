@@ -0,0 +1,247 @@
+use crate::codegen::currency_struct;
+use crate::countries::country_data::{CountryData, CurrencyData};
+use crate::currency_kind::CurrencyKind;
+use crate::data::read_data;
+use crate::validate::Diagnostics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+
+pub const FILE: &str = "currencies.json";
+const UNITS: &str = "currency_units.json";
+
+/// ISO 4217 currencies by code, sorted for the generated slice
+pub type Currencies = BTreeMap<String, CurrencyData>;
+
+// Every ISO 4217 currency with its minor unit and kind, curated from ISO 4217
+// itself, then the curated dates and withdrawn currencies, and cash increments
+// and narrow symbols, on top. With `iso_4217`, the names and numeric codes come
+// from the iso-codes project's ISO 4217 data, which is LGPL and so opt-in;
+// otherwise the names are the countries' and there are no numeric codes.
+pub fn get_currencies(
+    iso_4217: bool,
+    diagnostics: &mut Diagnostics,
+) -> Result<Currencies, Box<dyn Error>> {
+    let data = read_data("ISO_RS_CURRENCY_UNITS_PATH", UNITS)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let mut currencies = Currencies::new();
+    let records = parsed.get("currencies").and_then(Value::as_array);
+    for (record, units) in records.into_iter().flatten().enumerate() {
+        let Some(code) = units.get("alpha_3").and_then(Value::as_str) else {
+            diagnostics.issue_in(UNITS, record, "", "", "no alpha_3 code, skipped");
+            continue;
+        };
+        let currency = CurrencyData {
+            code: Some(code.to_string()),
+            ..currency_units(UNITS, record, units, diagnostics)
+        };
+        if currencies.insert(code.to_string(), currency).is_some() {
+            return Err(format!("currency code {code} is in {UNITS} more than once").into());
+        }
+    }
+    if iso_4217 {
+        add_iso_4217(&mut currencies, diagnostics)?;
+    }
+    add_history(&mut currencies, diagnostics)?;
+    add_usage(&mut currencies, diagnostics)?;
+    Ok(currencies)
+}
+
+// The minor unit and kind of a record, the fields ISO 4217 list one adds
+fn currency_units(
+    file: &str,
+    record: usize,
+    currency: &Value,
+    diagnostics: &mut Diagnostics,
+) -> CurrencyData {
+    let field = |key| currency.get(key).and_then(Value::as_str);
+    // Legal tender is most of the list, so it is left out of the data
+    let kind = match field("kind") {
+        Some(kind) => CurrencyKind::from_name(kind),
+        None => Some(CurrencyKind::LegalTender),
+    };
+    if kind.is_none() {
+        let message = "kind is not a known CurrencyKind, add it to src/currency_kind.rs";
+        let (name, code) = (field("name").unwrap_or_default(), field("alpha_3"));
+        diagnostics.issue_in(file, record, name, code.unwrap_or_default(), message);
+    }
+    let minor_unit = currency.get("minor_unit").and_then(Value::as_u64);
+    CurrencyData {
+        minor_unit: minor_unit.and_then(|digits| u8::try_from(digits).ok()),
+        kind,
+        ..Default::default()
+    }
+}
+
+fn currency_data(
+    file: &str,
+    record: usize,
+    currency: &Value,
+    diagnostics: &mut Diagnostics,
+) -> Option<CurrencyData> {
+    let field = |key| currency.get(key).and_then(Value::as_str);
+    let (Some(code), Some(name)) = (field("alpha_3"), field("name")) else {
+        diagnostics.issue_in(file, record, "", "", "no alpha_3 code or name, skipped");
+        return None;
+    };
+    Some(CurrencyData {
+        code: Some(code.to_string()),
+        name: Some(name.to_string()),
+        numeric_code: field("numeric").map(str::to_string),
+        ..currency_units(file, record, currency, diagnostics)
+    })
+}
+
+// Names and numeric codes, which iso-codes has for the same currencies
+fn add_iso_4217(
+    currencies: &mut Currencies,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), Box<dyn Error>> {
+    let data = read_data("ISO_RS_CURRENCIES_PATH", FILE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let mut named = HashSet::new();
+    let records = parsed.get("4217").and_then(Value::as_array);
+    for (record, iso) in records.into_iter().flatten().enumerate() {
+        let Some(iso) = currency_data(FILE, record, iso, diagnostics) else {
+            continue;
+        };
+        let code = iso.code.clone().unwrap_or_default();
+        if !named.insert(code.clone()) {
+            return Err(format!("currency code {code} is in {FILE} more than once").into());
+        }
+        let Some(currency) = currencies.get_mut(&code) else {
+            let name = iso.name.as_deref().unwrap_or_default();
+            let message = format!("not in {UNITS}, add its minor unit from ISO 4217 list one");
+            diagnostics.issue_in(FILE, record, name, &code, &message);
+            currencies.insert(code, iso);
+            continue;
+        };
+        currency.name = iso.name;
+        currency.numeric_code = iso.numeric_code;
+    }
+    Ok(())
+}
+
+// Dates and replacements, which also bring in the currencies ISO 4217 has withdrawn
+fn add_history(
+    currencies: &mut Currencies,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), Box<dyn Error>> {
+    const HISTORY: &str = "currency_history.json";
+    let data = read_data("ISO_RS_CURRENCY_HISTORY_PATH", HISTORY)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let records = parsed.get("currencies").and_then(Value::as_array);
+    let mut replacements = Vec::new();
+    for (record, history) in records.into_iter().flatten().enumerate() {
+        let field = |key| history.get(key).and_then(Value::as_str).map(str::to_string);
+        let code = field("alpha_3").unwrap_or_default();
+        let currency = match currencies.get_mut(&code) {
+            Some(currency) => currency,
+            None => match currency_data(HISTORY, record, history, diagnostics) {
+                Some(currency) => currencies.entry(code.clone()).or_insert(currency),
+                None => continue,
+            },
+        };
+        currency.introduced = field("introduced");
+        currency.withdrawn = field("withdrawn");
+        currency.replaced_by = field("replaced_by");
+        currency.conversion_rate = field("conversion_rate");
+        let name = currency.name.clone().unwrap_or_default();
+        match &currency.replaced_by {
+            Some(replaced_by) => replacements.push((record, name, code, replaced_by.clone())),
+            None if currency.conversion_rate.is_some() => {
+                diagnostics.issue_in(HISTORY, record, &name, &code, "rate without replaced_by")
+            }
+            None => {}
+        }
+    }
+    // Only checked once every record is in, as a replacement can come after
+    for (record, name, code, replaced_by) in replacements {
+        if !currencies.contains_key(&replaced_by) {
+            let message = format!("replaced_by {replaced_by} is not a known currency");
+            diagnostics.issue_in(HISTORY, record, &name, &code, &message);
+        }
+    }
+    Ok(())
+}
+
+// Cash increments and narrow symbols, for currencies already in
+fn add_usage(
+    currencies: &mut Currencies,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), Box<dyn Error>> {
+    const USAGE: &str = "currency_usage.json";
+    let data = read_data("ISO_RS_CURRENCY_USAGE_PATH", USAGE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let records = parsed.get("currencies").and_then(Value::as_array);
+    let mut narrow_symbols = BTreeMap::new();
+    for (record, usage) in records.into_iter().flatten().enumerate() {
+        let code = usage
+            .get("alpha_3")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let Some(currency) = currencies.get_mut(code) else {
+            diagnostics.issue_in(USAGE, record, "", code, "not a known currency, skipped");
+            continue;
+        };
+        let name = currency.name.clone().unwrap_or_default();
+        if let Some(increment) = usage.get("cash_increment") {
+            match increment
+                .as_u64()
+                .and_then(|increment| u32::try_from(increment).ok())
+            {
+                Some(increment) if increment > 1 => currency.cash_increment = Some(increment),
+                _ => {
+                    let message = "cash_increment is not a whole number above 1, skipped";
+                    diagnostics.issue_in(USAGE, record, &name, code, message);
+                }
+            }
+        }
+        if let Some(symbol) = usage.get("narrow_symbol") {
+            match symbol.as_str().filter(|symbol| !symbol.trim().is_empty()) {
+                Some(symbol) => {
+                    if let Some(other) = narrow_symbols.insert(symbol.to_string(), code.to_string())
+                    {
+                        let message = format!("narrow_symbol {symbol} is also {other}'s");
+                        diagnostics.issue_in(USAGE, record, &name, code, &message);
+                    }
+                    currency.narrow_symbol = Some(symbol.to_string());
+                }
+                None => {
+                    let message = "narrow_symbol is not a symbol, skipped";
+                    diagnostics.issue_in(USAGE, record, &name, code, message);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The table has no symbols, and no names without `iso_4217`, so each currency
+/// takes the first ones a country gives it, going by alpha_2 code.
+pub fn add_symbols(currencies: &mut Currencies, countries: &[CountryData]) {
+    for data in countries.iter().flat_map(|country| &country.currencies) {
+        let Some(currency) = data.code.as_ref().and_then(|code| currencies.get_mut(code)) else {
+            continue;
+        };
+        if let Some(symbol) = &data.symbol {
+            currency.symbol.get_or_insert_with(|| symbol.clone());
+        }
+        if let Some(name) = &data.name {
+            currency.name.get_or_insert_with(|| name.clone());
+        }
+    }
+}
+
+pub fn currencies_tokens(currencies: &Currencies) -> TokenStream {
+    let currencies = currencies.values().map(currency_struct);
+    quote! {
+        #[cfg(feature = "currencies")]
+        /// All the ISO 4217 currencies as [`Currency`](struct.Currency.html), including
+        /// withdrawn ones, sorted by code.
+        pub static CURRENCIES: &[Currency] = &[#(#currencies),*];
+    }
+}
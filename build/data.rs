@@ -0,0 +1,29 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+/// Whether the crate is built with a feature, eg. "ISO639", which cargo tells
+/// build scripts through the environment
+pub fn enabled(feature: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{feature}")).is_some()
+}
+
+/// Read a data file, from the path in `var` when it is set, otherwise from the
+/// copy bundled under `build/`.
+pub fn read_data(var: &str, file: &str) -> Result<String, Box<dyn Error>> {
+    println!("cargo:rerun-if-env-changed={var}");
+    let path = match env::var_os(var) {
+        Some(path) => {
+            // Outside the crate, so not covered by rerun-if-changed=build
+            let path = PathBuf::from(path);
+            println!("cargo:rerun-if-changed={}", path.display());
+            path
+        }
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("build")
+            .join(file),
+    };
+    fs::read_to_string(&path)
+        .map_err(|error| format!("could not read {} ({var}): {error}", path.display()).into())
+}
@@ -0,0 +1,12 @@
+#[derive(Debug)]
+pub struct HistoricData {
+    pub name: String,
+    pub alpha_2: String,
+    pub alpha_3: String,
+    pub alpha_4: String,
+    pub numeric_code: Option<String>,
+    pub introduced: String,
+    pub withdrawn: String,
+    pub comment: Option<String>,
+    pub successors: Vec<String>,
+}
@@ -0,0 +1,118 @@
+use crate::codegen::historic_struct;
+use crate::data::read_data;
+use crate::validate::Diagnostics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::collections::HashMap;
+use std::error::Error;
+
+pub mod historic_data;
+use historic_data::HistoricData;
+
+pub const FILE: &str = "historic_countries.json";
+const SUCCESSORS: &str = "successors.json";
+const INTRODUCED: &str = "introduced.json";
+
+/// When the first edition of ISO 3166 came out, with most of the codes
+const FIRST_EDITION: &str = "1974";
+
+// The curated files are objects keyed by alpha_4 code, read with the position
+// of each key so diagnostics can point at it.
+fn get_curated(var: &str, file: &str) -> Result<HashMap<String, (usize, Value)>, Box<dyn Error>> {
+    let data = read_data(var, file)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let entries = parsed.as_object().into_iter().flatten().enumerate();
+    let entries =
+        entries.map(|(record, (alpha_4, value))| (alpha_4.clone(), (record, value.clone())));
+    Ok(entries.collect())
+}
+
+// Whether a date is "YYYY" or "YYYY-MM-DD", like the withdrawal dates
+fn is_date(date: &str) -> bool {
+    let digits = |part: &str, len| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+    match date.split('-').collect::<Vec<_>>()[..] {
+        [year] => digits(year, 4),
+        [year, month, day] => digits(year, 4) && digits(month, 2) && digits(day, 2),
+        _ => false,
+    }
+}
+
+pub fn get_historic(diagnostics: &mut Diagnostics) -> Result<TokenStream, Box<dyn Error>> {
+    let data = read_data("ISO_RS_HISTORIC_PATH", FILE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    // ISO 3166-3 lists the codes but not what became of each country beyond the
+    // last two letters of its alpha_4 code, which are "HH" for any country that
+    // was split up, nor when the codes were first assigned. The successor states
+    // and introduction dates come from curated files instead.
+    let mut successors = get_curated("ISO_RS_SUCCESSORS_PATH", SUCCESSORS)?;
+    let mut introduced = get_curated("ISO_RS_INTRODUCED_PATH", INTRODUCED)?;
+    let mut countries = Vec::new();
+    let records = parsed.get("3166-3").and_then(Value::as_array);
+    for (record, country) in records.into_iter().flatten().enumerate() {
+        let field = |key| country.get(key).and_then(Value::as_str).map(str::to_string);
+        let (Some(name), Some(alpha_2), Some(alpha_3), Some(alpha_4), Some(withdrawn)) = (
+            field("name"),
+            field("alpha_2"),
+            field("alpha_3"),
+            field("alpha_4"),
+            field("withdrawal_date"),
+        ) else {
+            diagnostics.issue_in(FILE, record, "", "", "missing a code or date, skipped");
+            continue;
+        };
+        let successors = successors
+            .remove(&alpha_4)
+            .map(|(_, successors)| successors);
+        let successors = successors
+            .as_ref()
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten();
+        let successors: Vec<_> = successors
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+        if successors.is_empty() {
+            let message = format!("no successors in {SUCCESSORS}");
+            diagnostics.issue_in(FILE, record, &name, &alpha_4, &message);
+        }
+        let introduced = introduced.remove(&alpha_4).map(|(_, date)| date);
+        let introduced = match introduced.as_ref().and_then(Value::as_str) {
+            Some(date) if is_date(date) && withdrawn.get(..4) >= date.get(..4) => date.to_string(),
+            _ => {
+                let message = format!(
+                    "no valid introduction date in {INTRODUCED}, taken to be {FIRST_EDITION}"
+                );
+                diagnostics.issue_in(FILE, record, &name, &alpha_4, &message);
+                FIRST_EDITION.to_string()
+            }
+        };
+        countries.push(HistoricData {
+            name,
+            alpha_2,
+            alpha_3,
+            alpha_4,
+            numeric_code: field("numeric"),
+            introduced,
+            withdrawn,
+            comment: field("comment"),
+            successors,
+        });
+    }
+    let unused = [(SUCCESSORS, successors), (INTRODUCED, introduced)];
+    for (file, entries) in unused {
+        for (alpha_4, (record, _)) in entries {
+            let message = format!("not in {FILE}, unused");
+            diagnostics.issue_in(file, record, "", &alpha_4, &message);
+        }
+    }
+
+    countries.sort_by(|a, b| a.alpha_4.cmp(&b.alpha_4));
+    let countries = countries.iter().map(historic_struct);
+    Ok(quote! {
+        /// All the ISO 3166-3 formerly used country codes as [`HistoricCountry`](struct.HistoricCountry.html), sorted by alpha_4 code.
+        pub static HISTORIC_COUNTRIES: &[HistoricCountry] = &[#(#countries),*];
+    })
+}
@@ -0,0 +1,65 @@
+use crate::codegen::option_tokens;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+// FNV-1a, 64 bit. Not for security, just to tell two copies of the data apart.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Where the data files came from, for `iso_rs::data_info()`, from the contents
+// of countries.json as read and both files as parsed for the country tables
+pub fn get_info(
+    countries_data: &str,
+    countries: &Value,
+    timezones: &Value,
+) -> Result<TokenStream, Box<dyn Error>> {
+    let tzdata_version = option_tokens(&timezones.get("version").and_then(Value::as_str));
+    let tzdata_source = option_tokens(&timezones.get("source").and_then(Value::as_str));
+    let timezones = timezones
+        .get("zones")
+        .and_then(Value::as_array)
+        .map_or(0, Vec::len);
+
+    let countries_hash = format!("{:016x}", fnv1a(countries_data.as_bytes()));
+    let country_records = countries.as_array().map_or(0, Vec::len);
+    // The meta file describes the bundled countries.json, not an override. Only
+    // the override's file name is kept, as its full path is the builder's own.
+    let (countries_source, countries_date) = match env::var_os("ISO_RS_COUNTRIES_PATH") {
+        Some(path) => {
+            let name = Path::new(&path).file_name().unwrap_or_default();
+            (Some(name.to_string_lossy().into_owned()), None)
+        }
+        None => {
+            let meta: Value = from_str(&fs::read_to_string(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/build/countries.meta.json"
+            ))?)?;
+            let field = |key| meta.get(key).and_then(Value::as_str).map(str::to_string);
+            (field("source"), field("fetched"))
+        }
+    };
+    let countries_source = option_tokens(&countries_source);
+    let countries_date = option_tokens(&countries_date);
+
+    Ok(quote! {
+        static DATA_INFO: DataInfo = DataInfo {
+            tzdata_version: #tzdata_version,
+            tzdata_source: #tzdata_source,
+            timezones: #timezones,
+            countries_hash: #countries_hash,
+            countries_source: #countries_source,
+            countries_date: #countries_date,
+            country_records: #country_records,
+            countries: COUNTRIES.len(),
+        };
+    })
+}
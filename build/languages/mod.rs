@@ -0,0 +1,159 @@
+use crate::codegen::language_struct;
+use crate::countries::country_data::LanguageData;
+use crate::data::read_data;
+use crate::language_kind::{LanguageScope, LanguageType};
+use crate::scripts::Scripts;
+use crate::validate::Diagnostics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::collections::BTreeMap;
+use std::error::Error;
+
+pub const FILE: &str = "languages.json";
+
+/// ISO 639-3 languages by code, sorted for the generated slice
+pub type Languages = BTreeMap<String, LanguageData>;
+
+// The iso-codes project's ISO 639-3 data, with the macrolanguage of each
+// individual language and the script of common languages from curated files
+// since iso-codes doesn't have them. Every macrolanguage must have members.
+pub fn get_languages(
+    scripts: Option<&Scripts>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Languages, Box<dyn Error>> {
+    let data = read_data("ISO_RS_LANGUAGES_PATH", FILE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let mut languages = Languages::new();
+    let mut macrolanguages = Vec::new();
+    let records = parsed.get("639-3").and_then(Value::as_array);
+    for (record, language) in records.into_iter().flatten().enumerate() {
+        let field = |key| language.get(key).and_then(Value::as_str);
+        let (Some(code), Some(name)) = (field("alpha_3"), field("name")) else {
+            diagnostics.issue_in(FILE, record, "", "", "no alpha_3 code or name, skipped");
+            continue;
+        };
+        let scope = field("scope").and_then(LanguageScope::from_code);
+        let language_type = field("type").and_then(LanguageType::from_code);
+        if scope.is_none() || language_type.is_none() {
+            let message = "unknown scope or type, add it to src/language_kind.rs";
+            diagnostics.issue_in(FILE, record, name, code, message);
+        }
+        if scope == Some(LanguageScope::Macrolanguage) {
+            macrolanguages.push((record, name.to_string(), code.to_string()));
+        }
+        let part_2 = language.get("part_2").and_then(Value::as_bool) == Some(true);
+        let language = LanguageData {
+            iso639_1: field("alpha_2").map(str::to_string),
+            iso639_2: part_2.then(|| code.to_string()),
+            name: Some(name.to_string()),
+            native_name: None,
+            iso639_2b: field("bibliographic").map(str::to_string),
+            iso639_3: Some(code.to_string()),
+            scope,
+            language_type,
+            macrolanguage: None,
+            script: None,
+        };
+        if languages.insert(code.to_string(), language).is_some() {
+            return Err(format!("language code {code} is in {FILE} more than once").into());
+        }
+    }
+    add_macrolanguages(&mut languages, diagnostics)?;
+    // So that an empty members() means the data is missing, not the languages
+    for (record, name, code) in macrolanguages {
+        let mut members = languages.values();
+        if !members.any(|language| language.macrolanguage.as_ref() == Some(&code)) {
+            let message = "macrolanguage without members in macrolanguages.json";
+            diagnostics.issue_in(FILE, record, &name, &code, message);
+        }
+    }
+    add_scripts(&mut languages, scripts, diagnostics)?;
+    Ok(languages)
+}
+
+fn add_macrolanguages(
+    languages: &mut Languages,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), Box<dyn Error>> {
+    const MACROLANGUAGES: &str = "macrolanguages.json";
+    let data = read_data("ISO_RS_MACROLANGUAGES_PATH", MACROLANGUAGES)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let members = parsed.get("members").and_then(Value::as_object);
+    for (record, (macrolanguage, members)) in members.into_iter().flatten().enumerate() {
+        let scope = languages
+            .get(macrolanguage)
+            .and_then(|language| language.scope);
+        if scope != Some(LanguageScope::Macrolanguage) {
+            let message = "not a macrolanguage in languages.json, skipped";
+            diagnostics.issue_in(MACROLANGUAGES, record, "", macrolanguage, message);
+            continue;
+        }
+        for member in members.as_array().into_iter().flatten() {
+            let code = member.as_str().unwrap_or_default();
+            match languages.get_mut(code) {
+                Some(language) if language.scope == Some(LanguageScope::Individual) => {
+                    language.macrolanguage = Some(macrolanguage.clone());
+                }
+                _ => {
+                    let message = format!("member {code:?} is not an individual language");
+                    diagnostics.issue_in(MACROLANGUAGES, record, "", macrolanguage, &message);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// The scripts are only checked against scripts.json when `iso15924` reads it
+fn add_scripts(
+    languages: &mut Languages,
+    scripts: Option<&Scripts>,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), Box<dyn Error>> {
+    const LANGUAGE_SCRIPTS: &str = "language_scripts.json";
+    let data = read_data("ISO_RS_LANGUAGE_SCRIPTS_PATH", LANGUAGE_SCRIPTS)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let pairs = parsed.get("scripts").and_then(Value::as_object);
+    for (record, (code, script)) in pairs.into_iter().flatten().enumerate() {
+        let script = script.as_str().unwrap_or_default();
+        if scripts.is_some_and(|scripts| !scripts.contains_key(script)) {
+            let message = format!("script {script:?} is not in scripts.json, skipped");
+            diagnostics.issue_in(LANGUAGE_SCRIPTS, record, "", code, &message);
+            continue;
+        }
+        match languages.get_mut(code) {
+            Some(language) => language.script = Some(script.to_string()),
+            None => {
+                let message = "not a language in languages.json, skipped";
+                diagnostics.issue_in(LANGUAGE_SCRIPTS, record, "", code, message);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Country languages are joined by their ISO 639-2 code, or else their ISO 639-1 code
+pub fn find_language(languages: &Languages, code: &str) -> Option<LanguageData> {
+    let language = match code.len() {
+        2 => languages
+            .values()
+            .find(|language| language.iso639_1.as_deref() == Some(code)),
+        _ => languages.get(code).or_else(|| {
+            languages
+                .values()
+                .find(|language| language.iso639_2b.as_deref() == Some(code))
+        }),
+    };
+    language.cloned()
+}
+
+pub fn languages_tokens(languages: &Languages) -> TokenStream {
+    let languages = languages.values().map(language_struct);
+    quote! {
+        #[cfg(feature = "iso639")]
+        /// All the ISO 639-3 languages as [`Language`](struct.Language.html), sorted by ISO 639-3 code.
+        pub static LANGUAGES: &[Language] = &[#(#languages),*];
+    }
+}
@@ -0,0 +1,63 @@
+use crate::codegen::script_struct;
+use crate::data::read_data;
+use crate::validate::Diagnostics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::collections::BTreeMap;
+use std::error::Error;
+
+pub mod script_data;
+use script_data::ScriptData;
+
+pub const FILE: &str = "scripts.json";
+
+/// ISO 15924 scripts by alpha_4 code, sorted for the generated slice
+pub type Scripts = BTreeMap<String, ScriptData>;
+
+// The iso-codes project's ISO 15924 data, with the Unicode property value
+// aliases added from ISO 15924 itself since iso-codes doesn't have them.
+pub fn get_scripts(diagnostics: &mut Diagnostics) -> Result<Scripts, Box<dyn Error>> {
+    let data = read_data("ISO_RS_SCRIPTS_PATH", FILE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let mut scripts = Scripts::new();
+    let records = parsed.get("15924").and_then(Value::as_array);
+    for (record, script) in records.into_iter().flatten().enumerate() {
+        let field = |key| script.get(key).and_then(Value::as_str).map(str::to_string);
+        let (Some(alpha_4), Some(numeric_code), Some(name)) =
+            (field("alpha_4"), field("numeric"), field("name"))
+        else {
+            diagnostics.issue_in(FILE, record, "", "", "missing a code or name, skipped");
+            continue;
+        };
+        let well_formed = alpha_4.len() == 4
+            && alpha_4.starts_with(|c: char| c.is_ascii_uppercase())
+            && alpha_4[1..].bytes().all(|b| b.is_ascii_lowercase())
+            && numeric_code.len() == 3
+            && numeric_code.bytes().all(|b| b.is_ascii_digit());
+        if !well_formed {
+            diagnostics.issue_in(FILE, record, &name, &alpha_4, "malformed code, skipped");
+            continue;
+        }
+        let script = ScriptData {
+            alpha_4: alpha_4.clone(),
+            numeric_code,
+            name,
+            property_value_alias: field("property_value_alias"),
+        };
+        if scripts.insert(alpha_4.clone(), script).is_some() {
+            return Err(format!("script code {alpha_4} is in {FILE} more than once").into());
+        }
+    }
+    Ok(scripts)
+}
+
+pub fn scripts_tokens(scripts: &Scripts) -> TokenStream {
+    let scripts = scripts.values().map(script_struct);
+    quote! {
+        #[cfg(feature = "iso15924")]
+        /// All the ISO 15924 scripts as [`Script`](struct.Script.html), sorted by alpha_4 code.
+        pub static SCRIPTS: &[Script] = &[#(#scripts),*];
+    }
+}
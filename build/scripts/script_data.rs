@@ -0,0 +1,7 @@
+#[derive(Debug)]
+pub struct ScriptData {
+    pub alpha_4: String,
+    pub numeric_code: String,
+    pub name: String,
+    pub property_value_alias: Option<String>,
+}
@@ -0,0 +1,30 @@
+use serde_json::{Value, from_str};
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+
+/// Dependent territory alpha_2 code to the alpha_2 code of its sovereign state.
+pub type Sovereigns = HashMap<String, String>;
+
+// countries.json's `independent` flag alone is unreliable (Aruba and Pitcairn are
+// marked independent, Uzbekistan is null) and says nothing about who the parent
+// state is, so the relationships come from a curated file instead.
+pub fn get_sovereigns() -> Result<Sovereigns, Box<dyn Error>> {
+    let mut sovereigns = File::open(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/build/sovereigns.json"
+    ))
+    .unwrap();
+    let mut data = String::new();
+    sovereigns.read_to_string(&mut data).unwrap();
+    let parsed: Value = from_str(data.as_str())?;
+    let mut map: Sovereigns = HashMap::new();
+    for (territory, sovereign) in parsed.as_object().into_iter().flatten() {
+        if let Some(sovereign) = sovereign.as_str() {
+            map.insert(territory.to_string(), sovereign.to_string());
+        }
+    }
+    Ok(map)
+}
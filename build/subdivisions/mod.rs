@@ -0,0 +1,91 @@
+use crate::codegen::subdivision_struct;
+use crate::data::read_data;
+use crate::validate::Diagnostics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{Value, from_str};
+
+use std::collections::HashSet;
+use std::error::Error;
+
+pub mod subdivision_data;
+use subdivision_data::SubdivisionData;
+
+pub const FILE: &str = "subdivisions.json";
+
+// Subdivisions come from the iso-codes project's ISO 3166-2 data, which already
+// has the full codes, so unlike countries.json there is little to fix up.
+pub fn get_subdivisions(diagnostics: &mut Diagnostics) -> Result<TokenStream, Box<dyn Error>> {
+    let data = read_data("ISO_RS_SUBDIVISIONS_PATH", FILE)?;
+    let parsed: Value = from_str(data.as_str())?;
+    let mut subdivisions = Vec::new();
+    let records = parsed.get("3166-2").and_then(Value::as_array);
+    for (record, subdivision) in records.into_iter().flatten().enumerate() {
+        let field = |key| subdivision.get(key).and_then(Value::as_str);
+        let (Some(code), Some(name), Some(category)) =
+            (field("code"), field("name"), field("type"))
+        else {
+            diagnostics.issue_in(FILE, record, "", "", "no code, name or type, skipped");
+            continue;
+        };
+        let Some((country, _)) = code.split_once('-') else {
+            diagnostics.issue_in(
+                FILE,
+                record,
+                name,
+                code,
+                "code has no country prefix, skipped",
+            );
+            continue;
+        };
+        // Parents are given either in full, "GB-SCT", or without the country, "NX"
+        let parent = field("parent").map(|parent| match parent.contains('-') {
+            true => parent.to_string(),
+            false => format!("{country}-{parent}"),
+        });
+        subdivisions.push((
+            record,
+            SubdivisionData {
+                code: code.to_string(),
+                name: name.to_string(),
+                category: category.to_string(),
+                parent,
+            },
+        ));
+    }
+
+    // Sorted so a country's subdivisions are one run, found by binary search
+    subdivisions.sort_by(|(_, a), (_, b)| a.code.cmp(&b.code));
+    let codes = subdivisions
+        .iter()
+        .map(|(_, subdivision)| subdivision.code.as_str())
+        .collect::<HashSet<_>>();
+    if codes.len() != subdivisions.len() {
+        return Err(format!("a subdivision code in {FILE} is used more than once").into());
+    }
+    for (record, subdivision) in &subdivisions {
+        if let Some(parent) = subdivision
+            .parent
+            .as_deref()
+            .filter(|parent| !codes.contains(parent))
+        {
+            let message = format!("parent {parent:?} is not a subdivision");
+            diagnostics.issue_in(
+                FILE,
+                *record,
+                &subdivision.name,
+                &subdivision.code,
+                &message,
+            );
+        }
+    }
+
+    let subdivisions = subdivisions
+        .iter()
+        .map(|(_, subdivision)| subdivision_struct(subdivision));
+    Ok(quote! {
+        #[cfg(feature = "subdivisions")]
+        /// All the ISO 3166-2 subdivisions as [`Subdivision`](struct.Subdivision.html), sorted by code.
+        pub static SUBDIVISIONS: &[Subdivision] = &[#(#subdivisions),*];
+    })
+}
@@ -0,0 +1,7 @@
+#[derive(Debug)]
+pub struct SubdivisionData {
+    pub code: String,
+    pub name: String,
+    pub category: String,
+    pub parent: Option<String>,
+}
@@ -1,35 +1,31 @@
-use crate::codegen::*;
-use serde_json::{Value, from_str};
+use crate::countries::country_data::TimezoneData;
+use serde_json::Value;
 
 use std::collections::HashMap;
-use std::error::Error;
-use std::fs::File;
-use std::io::Read;
 
 pub type Timezones = HashMap<String, Vec<String>>;
 
-pub fn get_time() -> Result<Timezones, Box<dyn Error>> {
-    let mut timezones =
-        File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/build/timezones.json")).unwrap();
-    let mut data = String::new();
-    timezones.read_to_string(&mut data).unwrap();
+pub fn get_time(parsed: &Value) -> Timezones {
     let mut map: Timezones = HashMap::new();
-    let parsed: Value = from_str(data.as_str())?;
     let zones = parsed.get("zones").and_then(Value::as_array);
     for zone in zones.into_iter().flatten() {
-        if let (Some(code), Some(zone_name)) = (zone.get("countryCode"), zone.get("zoneName")) {
+        if let (Some(code), Some(zone_name)) = (
+            zone.get("countryCode").and_then(Value::as_str),
+            zone.get("zoneName").and_then(Value::as_str),
+        ) {
             map.entry(code.to_string())
                 .or_default()
                 .push(zone_name.to_string());
         }
     }
-    Ok(map)
+    map
 }
 
-pub fn timezone_vec(timezones: Vec<String>) -> Vec<String> {
-    let mut vec = Vec::new();
-    for timezone in timezones.iter() {
-        vec.push(timezone_struct(timezone.to_string()));
-    }
-    vec
+pub fn timezone_vec(timezones: &[String]) -> Vec<TimezoneData> {
+    timezones
+        .iter()
+        .map(|timezone| TimezoneData {
+            iana_identifier: timezone.to_string(),
+        })
+        .collect()
 }
@@ -0,0 +1,140 @@
+use crate::countries::country_data::CountryData;
+use crate::region::Region;
+use chrono_tz::Tz;
+
+use std::collections::HashSet;
+use std::env;
+use std::error::Error;
+
+/// Set to `1` or `true` to fail the build on any data issue instead of warning.
+pub const STRICT_VAR: &str = "ISO_RS_STRICT";
+
+/// Issues found in the data files, reported together once codegen is done.
+pub struct Diagnostics {
+    strict: bool,
+    issues: Vec<String>,
+}
+
+impl Diagnostics {
+    pub fn new(strict: bool) -> Self {
+        Self {
+            strict,
+            issues: Vec::new(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        println!("cargo:rerun-if-env-changed={STRICT_VAR}");
+        Self::new(env::var(STRICT_VAR).is_ok_and(|value| value == "1" || value == "true"))
+    }
+
+    // Record context first, so an issue can be traced back to the JSON by index
+    pub fn issue(&mut self, record: usize, name: &str, alpha_2: &str, message: &str) {
+        self.issue_in("countries.json", record, name, alpha_2, message);
+    }
+
+    pub fn issue_in(&mut self, file: &str, record: usize, name: &str, code: &str, message: &str) {
+        self.issues
+            .push(format!("{file}[{record}] {name:?} ({code}): {message}"));
+    }
+
+    // Warn about every issue, and in strict mode fail the build if there were any
+    pub fn finish(self) -> Result<(), Box<dyn Error>> {
+        for issue in &self.issues {
+            println!("cargo:warning={issue}");
+        }
+        if self.strict && !self.issues.is_empty() {
+            let count = self.issues.len();
+            return Err(
+                format!("{count} data issue(s) with {STRICT_VAR} set, see the warnings").into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Countries that IANA's zone.tab has no zone for: Bouvet Island and Heard
+/// Island are uninhabited, and Kosovo's code is user-assigned rather than ISO.
+pub const NO_TIMEZONES: &[&str] = &["BV", "HM", "XK"];
+
+/// Codes that key maps of single countries, where a repeat would shadow a
+/// country or fail the map's generation.
+#[derive(Default)]
+pub struct UniqueCodes<'a> {
+    alpha_2: HashSet<&'a str>,
+    alpha_3: HashSet<&'a str>,
+    ioc_code: HashSet<&'a str>,
+}
+
+impl<'a> UniqueCodes<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Errors on the first code another country already has
+    pub fn add(&mut self, country: &'a CountryData) -> Result<(), Box<dyn Error>> {
+        let codes = [
+            ("alpha_2", &mut self.alpha_2, Some(&country.alpha_2)),
+            ("alpha_3", &mut self.alpha_3, Some(&country.alpha_3)),
+            ("IOC", &mut self.ioc_code, country.ioc_code.as_ref()),
+        ];
+        for (kind, seen, code) in codes {
+            if let Some(code) = code.filter(|code| !seen.insert(code)) {
+                return Err(format!("{kind} code {code} is used by more than one country").into());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_code(code: &str, len: usize) -> bool {
+    code.len() == len && code.bytes().all(|byte| byte.is_ascii_uppercase())
+}
+
+// Checks on a single record that has made it into the generated maps
+pub fn validate_country(record: usize, country: &CountryData, diagnostics: &mut Diagnostics) {
+    let mut issue = |message: String| {
+        diagnostics.issue(record, &country.name, &country.alpha_2, &message);
+    };
+    if !is_code(&country.alpha_2, 2) {
+        issue(format!(
+            "alpha_2 code {:?} is not two uppercase letters",
+            country.alpha_2
+        ));
+    }
+    if !is_code(&country.alpha_3, 3) {
+        issue(format!(
+            "alpha_3 code {:?} is not three uppercase letters",
+            country.alpha_3
+        ));
+    }
+    match &country.region {
+        Some(region) if Region::from_name(region).is_none() => issue(format!(
+            "region {region:?} is not a known region, add it to src/region.rs"
+        )),
+        None => issue(String::from("no region")),
+        _ => {}
+    }
+    if country.timezones.is_empty() && !NO_TIMEZONES.contains(&country.alpha_2.as_str()) {
+        issue(String::from("no timezones in timezones.json"));
+    }
+    for timezone in &country.timezones {
+        if timezone.iana_identifier.parse::<Tz>().is_err() {
+            issue(format!(
+                "timezone {:?} does not exist in chrono-tz",
+                timezone.iana_identifier
+            ));
+        }
+    }
+    for code in country
+        .currencies
+        .iter()
+        .filter_map(|currency| currency.code.as_ref())
+    {
+        if !is_code(code, 3) {
+            issue(format!(
+                "currency code {code:?} is not three uppercase letters"
+            ));
+        }
+    }
+}
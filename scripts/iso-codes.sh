@@ -0,0 +1,89 @@
+# Shared by the update-*.sh scripts that refresh the data taken from the
+# iso-codes project: sourced, not run.
+#
+# iso-codes is LGPL-2.1-or-later, see LICENSE-LGPL. Every file written here
+# records the version, source and license it came from in its header.
+#
+# ISO_CODES_VERSION picks the release to fetch. ISO_CODES_DIR reads the JSON
+# files from a local copy instead, eg. /usr/share/iso-codes/json where the
+# distribution packages iso-codes; set ISO_CODES_VERSION to the release it is.
+
+ISO_CODES_VERSION="${ISO_CODES_VERSION:-4.15.0}"
+ISO_CODES_SOURCE="https://salsa.debian.org/iso-codes-team/iso-codes"
+ISO_CODES_LICENSE="LGPL-2.1-or-later"
+
+root="$(cd -- "$(dirname -- "${BASH_SOURCE[0]}")/.." && pwd)"
+
+for dep in curl jq; do
+  command -v "$dep" >/dev/null || { echo "error: $dep is required" >&2; exit 1; }
+done
+
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+# fetch iso_4217.json: download one of the iso-codes data files into $tmp
+fetch() {
+  local name="$1"
+  if [ -n "${ISO_CODES_DIR:-}" ]; then
+    echo "reading $ISO_CODES_DIR/$name"
+    cp "$ISO_CODES_DIR/$name" "$tmp/$name"
+  else
+    local url="$ISO_CODES_SOURCE/-/raw/v$ISO_CODES_VERSION/data/$name"
+    echo "fetching $url"
+    curl -fsSL --retry 3 --max-time 120 "$url" -o "$tmp/$name"
+  fi
+}
+
+# check file message filter [jq args]: fail with the lines the jq filter prints,
+# if any
+check() {
+  local file="$1" message="$2" filter="$3"
+  shift 3
+  local offenders
+  offenders="$(jq -r "$@" "$filter" "$file")"
+  if [ -n "$offenders" ]; then
+    echo "error: $message" >&2
+    printf '%s\n' "$offenders" | head -20 | sed 's/^/  /' >&2
+    exit 1
+  fi
+}
+
+# header [note]: the version, source and license header, as a JSON object
+header() {
+  jq -n --arg version "$ISO_CODES_VERSION" --arg source "$ISO_CODES_SOURCE" \
+    --arg license "$ISO_CODES_LICENSE" --arg note "${1:-}" \
+    '{version: $version, source: $source, license: $license}
+     + if $note == "" then {} else {note: $note} end'
+}
+
+# report target file key id: print the codes added and removed since the
+# current target, then move the new file over it
+report() {
+  local target="$1" file="$2" key="$3" id="$4"
+  if [ -f "$target" ]; then
+    local before after added removed
+    before="$(jq -r --arg key "$key" ".[\$key][].$id" "$target" | sort)"
+    after="$(jq -r --arg key "$key" ".[\$key][].$id" "$file" | sort)"
+    added="$(comm -13 <(echo "$before") <(echo "$after") | tr '\n' ' ')"
+    removed="$(comm -23 <(echo "$before") <(echo "$after") | tr '\n' ' ')"
+    echo "$key: $(echo "$before" | wc -l) -> $(echo "$after" | wc -l)"
+    [ -n "${added// }" ] && echo "  added:   $added"
+    [ -n "${removed// }" ] && echo "  removed: $removed"
+  fi
+  mv "$file" "$target"
+  echo "wrote $target"
+  echo "run 'cargo test' to regenerate and verify"
+}
+
+# one_per_line header key records: write the header with one record per line,
+# for the files too long to diff with jq's one field per line
+one_per_line() {
+  local header="$1" key="$2" records="$3"
+  echo "{"
+  jq -r 'to_entries[] | "  \(.key | tojson): \(.value | tojson),"' "$header"
+  echo "  \"$key\": ["
+  jq -r '.[] | "{" + (to_entries | map("\(.key | tojson): \(.value | tojson)") | join(", ")) + "}"' \
+    "$records" | sed '$!s/$/,/; s/^/    /'
+  echo "  ]"
+  echo "}"
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+#
+# Refresh build/currencies.json from the ISO 4217 data of iso-codes. See
+# scripts/iso-codes.sh for where it is fetched from.
+#
+# iso-codes has no minor units or kinds, which are curated from ISO 4217 list
+# one in build/currency_units.json. A currency new to iso-codes has neither: the
+# script stops and names it, to be added there by hand.
+
+set -euo pipefail
+source "$(dirname -- "${BASH_SOURCE[0]}")/iso-codes.sh"
+
+target="$root/build/currencies.json"
+units="$root/build/currency_units.json"
+
+fetch iso_4217.json
+jq '.["4217"]' "$tmp/iso_4217.json" >"$tmp/records.json"
+
+check "$tmp/records.json" "currencies without a minor unit, add them to $units from ISO 4217 list one" "
+  (\$units[0].currencies | map(.alpha_3)) as \$known
+  | .[] | select(.alpha_3 as \$code | \$known | index(\$code) | not) | \"\(.alpha_3) \(.name)\"" \
+  --slurpfile units "$units"
+check "$tmp/records.json" "malformed alpha_3 or numeric codes" '
+  .[] | select((.alpha_3 | test("^[A-Z]{3}$") | not) or (.numeric | test("^[0-9]{3}$") | not)) | .alpha_3'
+
+header >"$tmp/header.json"
+one_per_line "$tmp/header.json" 4217 "$tmp/records.json" >"$tmp/currencies.json"
+
+report "$target" "$tmp/currencies.json" 4217 alpha_3
@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+#
+# Refresh build/historic_countries.json from the ISO 3166-3 data of iso-codes,
+# as is. See scripts/iso-codes.sh for where it is fetched from.
+
+set -euo pipefail
+source "$(dirname -- "${BASH_SOURCE[0]}")/iso-codes.sh"
+
+target="$root/build/historic_countries.json"
+
+fetch iso_3166-3.json
+header >"$tmp/header.json"
+jq --slurpfile header "$tmp/header.json" '$header[0] + {"3166-3": .["3166-3"]}' \
+  "$tmp/iso_3166-3.json" >"$tmp/historic_countries.json"
+
+# alpha_4 keys the generated lookup, and the withdrawal date is what makes a
+# country historic
+check "$tmp/historic_countries.json" "records missing alpha_4, name or withdrawal_date" '
+  .["3166-3"][]
+  | select((.alpha_4 // "") == "" or (.name // "") == "" or (.withdrawal_date // "") == "")
+  | .alpha_4 // .name // "record"'
+check "$tmp/historic_countries.json" "duplicate alpha_4 codes" '
+  .["3166-3"] | group_by(.alpha_4)[] | select(length > 1) | .[0].alpha_4'
+
+report "$target" "$tmp/historic_countries.json" 3166-3 alpha_4
@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+#
+# Refresh build/languages.json from the ISO 639-3 data of iso-codes. See
+# scripts/iso-codes.sh for where it is fetched from.
+#
+# part_2 marks the codes that are also ISO 639-2 terminology codes, which the
+# build needs for Language::from_iso639_2, taken from the ISO 639-2 data of the
+# same release. language_scripts.json and macrolanguages.json are kept by hand.
+
+set -euo pipefail
+source "$(dirname -- "${BASH_SOURCE[0]}")/iso-codes.sh"
+
+target="$root/build/languages.json"
+note="ISO 639-3 data of iso-codes, with part_2 added to the codes that are also ISO 639-2 terminology codes, from its ISO 639-2 data"
+
+fetch iso_639-3.json
+fetch iso_639-2.json
+
+# Keys are kept sorted, like iso-codes has them
+jq --slurpfile part_2 "$tmp/iso_639-2.json" '
+  ($part_2[0]["639-2"] | map({key: .alpha_3, value: true}) | from_entries) as $part_2
+  | .["639-3"]
+  | map(if $part_2[.alpha_3] then . + {part_2: true} | to_entries | sort_by(.key) | from_entries
+        else . end)' "$tmp/iso_639-3.json" >"$tmp/records.json"
+
+check "$tmp/records.json" "records missing alpha_3, name, scope or type" '
+  .[] | select((.alpha_3 // "") == "" or (.name // "") == "" or (.scope // "") == "" or (.type // "") == "")
+  | .alpha_3 // .name // "record"'
+check "$tmp/records.json" "duplicate alpha_3 codes" '
+  group_by(.alpha_3)[] | select(length > 1) | .[0].alpha_3'
+
+header "$note" >"$tmp/header.json"
+one_per_line "$tmp/header.json" 639-3 "$tmp/records.json" >"$tmp/languages.json"
+
+report "$target" "$tmp/languages.json" 639-3 alpha_3
@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+#
+# Refresh build/scripts.json from the ISO 15924 data of iso-codes. See
+# scripts/iso-codes.sh for where it is fetched from.
+#
+# iso-codes has no Unicode property value aliases, so they are added from the
+# code list of the ISO 15924 registration authority, which has a column for
+# them. The list is free to use; ISO15924_URL points at another copy of it.
+
+set -euo pipefail
+source "$(dirname -- "${BASH_SOURCE[0]}")/iso-codes.sh"
+
+ISO15924_URL="${ISO15924_URL:-https://www.unicode.org/iso15924/iso15924.txt}"
+
+target="$root/build/scripts.json"
+note="property_value_alias is not in iso-codes, it is from the ISO 15924 code list of the registration authority, $ISO15924_URL"
+
+fetch iso_15924.json
+echo "fetching $ISO15924_URL"
+curl -fsSL --retry 3 --max-time 120 "$ISO15924_URL" -o "$tmp/iso15924.txt"
+
+# The code list is: code;number;english name;french name;alias;version;date,
+# with comments starting with #
+jq -R -s '
+  split("\n")
+  | map(sub("\r$"; "") | select(length > 0 and (startswith("#") | not)) | split(";"))
+  | map(select(length >= 5 and .[4] != "") | {key: .[0], value: .[4]})
+  | from_entries' "$tmp/iso15924.txt" >"$tmp/aliases.json"
+jq -e 'length >= 100' "$tmp/aliases.json" >/dev/null \
+  || { echo "error: expected at least 100 aliases in $ISO15924_URL" >&2; exit 1; }
+
+header "$note" >"$tmp/header.json"
+jq --slurpfile header "$tmp/header.json" --slurpfile aliases "$tmp/aliases.json" '
+  .["15924"]
+  | map(. + if $aliases[0][.alpha_4] then {property_value_alias: $aliases[0][.alpha_4]} else {} end)
+  | $header[0] + {"15924": .}' "$tmp/iso_15924.json" >"$tmp/scripts.json"
+
+check "$tmp/scripts.json" "malformed alpha_4 or numeric codes" '
+  .["15924"][] | select((.alpha_4 | test("^[A-Z][a-z]{3}$") | not) or (.numeric | test("^[0-9]{3}$") | not))
+  | .alpha_4'
+
+report "$target" "$tmp/scripts.json" 15924 alpha_4
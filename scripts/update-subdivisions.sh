@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+#
+# Refresh build/subdivisions.json from the ISO 3166-2 data of iso-codes, as is.
+# See scripts/iso-codes.sh for where it is fetched from.
+
+set -euo pipefail
+source "$(dirname -- "${BASH_SOURCE[0]}")/iso-codes.sh"
+
+target="$root/build/subdivisions.json"
+
+fetch iso_3166-2.json
+header >"$tmp/header.json"
+jq --slurpfile header "$tmp/header.json" '$header[0] + {"3166-2": .["3166-2"]}' \
+  "$tmp/iso_3166-2.json" >"$tmp/subdivisions.json"
+
+# The build groups subdivisions by the country code before the hyphen
+check "$tmp/subdivisions.json" "subdivisions without a code like XX-..., name or type" '
+  .["3166-2"][]
+  | select((.code | test("^[A-Z]{2}-[A-Z0-9]{1,3}$") | not) or (.name // "") == "" or (.type // "") == "")
+  | .code // "record without a code"'
+check "$tmp/subdivisions.json" "duplicate subdivision codes" '
+  .["3166-2"] | group_by(.code)[] | select(length > 1) | .[0].code'
+
+report "$target" "$tmp/subdivisions.json" 3166-2 code
@@ -0,0 +1,162 @@
+use crate::{COUNTRIES, CURRENCIES, Country, Currency};
+
+impl Currency {
+    /// Get an iterator over all the ISO 4217 currencies, sorted by code,
+    /// including those not used by any country like XAU or XDR, and withdrawn
+    /// ones like DEM
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        CURRENCIES.iter()
+    }
+    /// Get the ISO 4217 currency with a code
+    ///
+    /// Its name is the one in ISO 4217 with the `iso4217` feature, and
+    /// otherwise the first one given by a country using it, if any, like its
+    /// symbol.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{CurrencyKind, prelude::*};
+    ///
+    /// let yen = Currency::from_code("JPY").unwrap();
+    /// assert_eq!(yen.minor_unit, Some(0));
+    ///
+    /// let gold = Currency::from_code("XAU").unwrap();
+    /// assert_eq!(gold.kind, Some(CurrencyKind::PreciousMetal));
+    /// assert_eq!(gold.minor_unit, None);
+    ///
+    /// let mark = Currency::from_code("DEM").unwrap();
+    /// assert!(!mark.is_active());
+    /// assert_eq!(mark.replaced_by, Some("EUR"));
+    /// assert_eq!(mark.conversion_rate, Some("1.95583"));
+    /// ```
+    pub fn from_code(code: &str) -> Option<&'static Self> {
+        let index = CURRENCIES
+            .binary_search_by_key(&Some(code), |currency| currency.code)
+            .ok()?;
+        Some(&CURRENCIES[index])
+    }
+    /// Get the countries using the currency, sorted by alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let franc = Currency::from_code("XOF").unwrap();
+    /// assert!(franc.countries().iter().any(|country| country.alpha_2 == "SN"));
+    /// ```
+    pub fn countries(&self) -> Vec<&'static Country> {
+        COUNTRIES
+            .iter()
+            .filter(|country| {
+                country
+                    .currencies
+                    .iter()
+                    .any(|currency| currency.code.is_some() && currency.code == self.code)
+            })
+            .collect()
+    }
+    /// Whether the currency is still in ISO 4217, rather than withdrawn
+    pub fn is_active(&self) -> bool {
+        self.withdrawn.is_none()
+    }
+    /// Get the currency that replaced a withdrawn one, following replacements
+    /// until one is active
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let bolivar = Currency::from_code("VEB").unwrap();
+    /// assert_eq!(bolivar.replaced_by, Some("VEF"));
+    /// assert_eq!(bolivar.replacement().unwrap().code, Some("VES"));
+    /// ```
+    pub fn replacement(&self) -> Option<&'static Currency> {
+        let mut replacement = Self::from_code(self.replaced_by?)?;
+        while let Some(next) = replacement.replaced_by.and_then(Self::from_code) {
+            replacement = next;
+        }
+        Some(replacement)
+    }
+    /// Smallest amount paid in cash, in minor units, where coins for the minor
+    /// unit aren't in use, eg. 5 for CHF as prices are rounded to 0.05 at the
+    /// till, and 1 otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// assert_eq!(Currency::from_code("CHF").unwrap().cash_increment(), 5);
+    /// assert_eq!(Currency::from_code("EUR").unwrap().cash_increment(), 1);
+    /// ```
+    pub fn cash_increment(&self) -> i64 {
+        self.cash_increment.map_or(1, i64::from)
+    }
+    /// Get the active currencies with a symbol, sorted by code, going by the
+    /// symbols countries give them and their [`narrow_symbol`](#method.narrow_symbol)
+    ///
+    /// Currencies missing from ISO 4217, like the Faroese króna, are included
+    /// as a country has them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let dollars = Currency::from_symbol("$");
+    /// assert!(dollars.iter().any(|currency| currency.code == Some("USD")));
+    /// assert!(dollars.iter().any(|currency| currency.code == Some("AUD")));
+    ///
+    /// let us_dollar = Currency::from_symbol("US$");
+    /// assert_eq!(us_dollar[0].code, Some("USD"));
+    /// ```
+    pub fn from_symbol(symbol: &str) -> Vec<&'static Self> {
+        let narrow = Self::iter().filter(|currency| currency.narrow_symbol() == Some(symbol));
+        let in_countries = COUNTRIES
+            .iter()
+            .flat_map(|country| country.currencies)
+            .filter(|currency| currency.symbol == Some(symbol))
+            .map(|currency| currency.code.and_then(Self::from_code).unwrap_or(currency));
+        let mut currencies: Vec<&'static Self> = narrow
+            .chain(in_countries)
+            .filter(|currency| currency.is_active())
+            .collect();
+        currencies.sort_by_key(|currency| currency.code);
+        currencies.dedup_by_key(|currency| currency.code);
+        currencies
+    }
+    /// Get the currency a symbol stands for in a country, eg. "$" in Canada,
+    /// or the only currency with the symbol, if the country has none of them
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let canada = Country::from_name("Canada").unwrap();
+    /// assert_eq!(Currency::resolve_symbol("$", canada).unwrap().code, Some("CAD"));
+    /// assert_eq!(Currency::resolve_symbol("€", canada).unwrap().code, Some("EUR"));
+    /// assert_eq!(Currency::resolve_symbol("£", canada), None);
+    /// ```
+    pub fn resolve_symbol(symbol: &str, country: &Country) -> Option<&'static Self> {
+        let candidates = Self::from_symbol(symbol);
+        let in_country = country.currencies.iter().find_map(|used| {
+            let code = used.code?;
+            candidates
+                .iter()
+                .find(|currency| currency.code == Some(code))
+        });
+        match (in_country, candidates.as_slice()) {
+            (Some(currency), _) | (None, [currency]) => Some(currency),
+            _ => None,
+        }
+    }
+    /// A symbol that tells the currency apart from others sharing its symbol, as
+    /// used outside its home countries, eg. "US$", "CA$" and "A$" for dollars
+    pub fn narrow_symbol(&self) -> Option<&'static str> {
+        self.narrow_symbol
+    }
+}
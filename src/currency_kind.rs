@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// What an ISO 4217 code stands for, as found in `Currency::kind`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CurrencyKind {
+    /// "Legal tender", money of one or more countries, eg. EUR
+    LegalTender,
+    /// "Fund", a unit indexed to or paired with a currency, eg. CLF or USN
+    Fund,
+    /// "Precious metal", a troy ounce of it, eg. XAU for gold
+    PreciousMetal,
+    /// "Unit of account", eg. XDR, the IMF's special drawing right
+    UnitOfAccount,
+    /// "Testing", XTS, reserved for tests
+    Testing,
+    /// "No currency", XXX, for transactions without one
+    NoCurrency,
+}
+
+impl CurrencyKind {
+    /// All the kinds
+    pub const ALL: [CurrencyKind; 6] = [
+        CurrencyKind::LegalTender,
+        CurrencyKind::Fund,
+        CurrencyKind::PreciousMetal,
+        CurrencyKind::UnitOfAccount,
+        CurrencyKind::Testing,
+        CurrencyKind::NoCurrency,
+    ];
+
+    /// Get the kind from its name, as returned by `as_str`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::CurrencyKind;
+    ///
+    /// assert_eq!(CurrencyKind::from_name("Precious metal"), Some(CurrencyKind::PreciousMetal));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == name)
+    }
+
+    /// Name of the kind, eg. "Precious metal"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CurrencyKind::LegalTender => "Legal tender",
+            CurrencyKind::Fund => "Fund",
+            CurrencyKind::PreciousMetal => "Precious metal",
+            CurrencyKind::UnitOfAccount => "Unit of account",
+            CurrencyKind::Testing => "Testing",
+            CurrencyKind::NoCurrency => "No currency",
+        }
+    }
+}
+
+impl fmt::Display for CurrencyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::country_data::{CountryData, CurrencyData, LanguageData, TimezoneData};
+use crate::parse::parse_countries;
+use crate::records::{Records, country_lookups};
+#[cfg(any(feature = "timezones", feature = "sovereignty"))]
+use crate::{COUNTRIES, Country};
+use serde_json::Value;
+use std::fmt;
+use std::io::Read;
+
+/// Countries loaded at runtime from JSON in the v2 restcountries schema, the
+/// same schema the crate's own data is generated from, with the same lookups
+/// as [`Country`](struct.Country.html).
+///
+/// The schema has no timezones or sovereign states, so those are taken from
+/// the built in country with the same alpha_2 code, if any, and the ISO 4217
+/// fields of currencies and ISO 639 fields of languages from the built in ones
+/// with the same code. Records without a name or repeating an earlier name
+/// are skipped, like in the build, and returned along with the dataset.
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::Dataset;
+///
+/// let json = r#"[{"name": "Atlantis", "alpha2Code": "XA", "alpha3Code": "XAT", "capital": "Poseidonia"},
+///                {"name": "Atlantis", "alpha2Code": "XB", "alpha3Code": "XBT"}]"#;
+/// let (dataset, skipped) = Dataset::from_json_reader(json.as_bytes()).unwrap();
+/// assert_eq!(dataset.from_alpha_2("XA").unwrap().name, "Atlantis");
+/// assert_eq!(dataset.lookup("poseidonia").unwrap().country.alpha_3, "XAT");
+/// assert_eq!(skipped[0].record, 1);
+/// assert_eq!(skipped[0].alpha_2, "XB");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dataset {
+    records: Records,
+}
+
+/// A record [`Dataset::from_json_reader`](struct.Dataset.html#method.from_json_reader)
+/// could not use, and why
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SkippedRecord {
+    /// Index of the record in the JSON array
+    pub record: usize,
+    /// Name of the record, empty if it has none
+    pub name: String,
+    /// ISO 3166-1 alpha_2 code of the record, empty if it has none
+    pub alpha_2: String,
+    /// Why the record was skipped, eg. "no name, skipped"
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            record,
+            name,
+            alpha_2,
+            reason,
+        } = self;
+        write!(f, "[{record}] {name:?} ({alpha_2}): {reason}")
+    }
+}
+
+impl Dataset {
+    /// Parse a JSON array of countries in the v2 restcountries schema, along
+    /// with the records that had to be skipped
+    pub fn from_json_reader<R: Read>(reader: R) -> serde_json::Result<(Self, Vec<SkippedRecord>)> {
+        let parsed: Value = serde_json::from_reader(reader)?;
+        let mut skipped = Vec::new();
+        let countries = parse_countries(
+            &parsed,
+            &built_in_timezones,
+            &built_in_sovereign,
+            &built_in_currency,
+            &built_in_language,
+            &mut |record, name, alpha_2, reason| {
+                skipped.push(SkippedRecord {
+                    record,
+                    name: name.to_string(),
+                    alpha_2: alpha_2.to_string(),
+                    reason: reason.to_string(),
+                })
+            },
+        );
+        let countries = countries.into_iter().map(|(_, country)| country).collect();
+        let dataset = Self {
+            records: Records::new(countries),
+        };
+        Ok((dataset, skipped))
+    }
+
+    country_lookups!();
+}
+
+impl<'a> IntoIterator for &'a Dataset {
+    type Item = &'a CountryData;
+    type IntoIter = std::slice::Iter<'a, CountryData>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(any(feature = "timezones", feature = "sovereignty"))]
+fn built_in(alpha_2: &str) -> Option<&'static Country> {
+    COUNTRIES.iter().find(|country| country.alpha_2 == alpha_2)
+}
+
+// Timezones of the built in country with the alpha_2 code, if the crate has them
+#[cfg(feature = "timezones")]
+fn built_in_timezones(alpha_2: &str) -> Vec<TimezoneData> {
+    built_in(alpha_2)
+        .map(|country| country.timezones)
+        .unwrap_or_default()
+        .iter()
+        .map(|timezone| TimezoneData {
+            iana_identifier: timezone.iana_identifier.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "timezones"))]
+fn built_in_timezones(_alpha_2: &str) -> Vec<TimezoneData> {
+    Vec::new()
+}
+
+// Sovereign state of the built in country with the alpha_2 code, if the crate has them
+#[cfg(feature = "sovereignty")]
+fn built_in_sovereign(alpha_2: &str) -> Option<String> {
+    built_in(alpha_2)
+        .and_then(|country| country.sovereign)
+        .map(str::to_string)
+}
+
+#[cfg(not(feature = "sovereignty"))]
+fn built_in_sovereign(_alpha_2: &str) -> Option<String> {
+    None
+}
+
+#[cfg(feature = "currencies")]
+fn built_in_currency(code: &str) -> Option<CurrencyData> {
+    let owned = |value: Option<&str>| value.map(str::to_string);
+    crate::Currency::from_code(code).map(|currency| CurrencyData {
+        numeric_code: owned(currency.numeric_code),
+        minor_unit: currency.minor_unit,
+        kind: currency.kind,
+        introduced: owned(currency.introduced),
+        withdrawn: owned(currency.withdrawn),
+        replaced_by: owned(currency.replaced_by),
+        conversion_rate: owned(currency.conversion_rate),
+        cash_increment: currency.cash_increment,
+        narrow_symbol: owned(currency.narrow_symbol),
+        ..Default::default()
+    })
+}
+
+#[cfg(not(feature = "currencies"))]
+fn built_in_currency(_code: &str) -> Option<CurrencyData> {
+    None
+}
+
+#[cfg(feature = "iso639")]
+fn built_in_language(code: &str) -> Option<LanguageData> {
+    let owned = |value: Option<&str>| value.map(str::to_string);
+    crate::Language::from_code(code).map(|language| LanguageData {
+        iso639_2b: owned(language.iso639_2b),
+        iso639_3: owned(language.iso639_3),
+        scope: language.scope,
+        language_type: language.language_type,
+        macrolanguage: owned(language.macrolanguage),
+        script: owned(language.script),
+        ..Default::default()
+    })
+}
+
+#[cfg(not(feature = "iso639"))]
+fn built_in_language(_code: &str) -> Option<LanguageData> {
+    None
+}
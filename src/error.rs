@@ -0,0 +1,242 @@
+use crate::{Country, CountryData, MatchKind};
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by the `Result` based lookups, like
+/// [`Country::try_lookup`](struct.Country.html#method.try_lookup), saying why
+/// nothing was found.
+///
+/// `C` is how the candidates of an ambiguous input are referred to, which is
+/// only something other than `&'static Country` for the lookups of a
+/// [`Registry`](struct.Registry.html) or a runtime `Dataset`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError<C = &'static Country> {
+    /// The input can't be the kind of key asked for, eg. "I1" as an alpha_2 code
+    Malformed {
+        /// The input as given
+        input: String,
+        /// The kind of key the input was expected to be
+        kind: MatchKind,
+    },
+    /// The input is a well formed code, but no country is assigned to it
+    Unassigned {
+        /// The input as given
+        input: String,
+        /// The kind of code the input was read as
+        kind: MatchKind,
+    },
+    /// The lookup needs a Cargo feature that is turned off
+    FeatureDisabled {
+        /// The input as given
+        input: String,
+        /// Name of the feature to turn on
+        feature: &'static str,
+    },
+    /// The input matches more than one country, eg. "Kingston"
+    Ambiguous {
+        /// The input as given
+        input: String,
+        /// The kind of key the countries matched by
+        kind: MatchKind,
+        /// Every country the input matches, sorted by alpha_2 code
+        candidates: Vec<C>,
+    },
+    /// No country goes by the input
+    NotFound {
+        /// The input as given
+        input: String,
+        /// Names of countries spelled closest to the input, best first
+        suggestions: Vec<String>,
+    },
+}
+
+impl<C> LookupError<C> {
+    /// The input that failed to match
+    pub fn input(&self) -> &str {
+        match self {
+            LookupError::Malformed { input, .. }
+            | LookupError::Unassigned { input, .. }
+            | LookupError::FeatureDisabled { input, .. }
+            | LookupError::Ambiguous { input, .. }
+            | LookupError::NotFound { input, .. } => input,
+        }
+    }
+
+    /// Names of countries the input may have meant, empty unless nothing was found
+    pub fn suggestions(&self) -> &[String] {
+        match self {
+            LookupError::NotFound { suggestions, .. } => suggestions,
+            _ => &[],
+        }
+    }
+}
+
+impl<C> LookupError<C> {
+    // Write the error, getting the names of any candidates from `name`
+    fn write(&self, f: &mut fmt::Formatter<'_>, name: impl Fn(&C) -> &str) -> fmt::Result {
+        match self {
+            LookupError::Malformed { input, kind } => {
+                write!(f, "{input:?} is not a well formed {kind}")
+            }
+            LookupError::Unassigned { input, kind } => {
+                write!(f, "no country is assigned the {kind} {input:?}")
+            }
+            LookupError::FeatureDisabled { input, feature } => {
+                write!(
+                    f,
+                    "can't look up {input:?}, the `{feature}` feature is disabled"
+                )
+            }
+            LookupError::Ambiguous {
+                input,
+                kind,
+                candidates,
+            } => {
+                write!(f, "{input:?} is the {kind} of ")?;
+                for (i, country) in candidates.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(if i == candidates.len() - 1 {
+                            " and "
+                        } else {
+                            ", "
+                        })?;
+                    }
+                    f.write_str(name(country))?;
+                }
+                Ok(())
+            }
+            LookupError::NotFound { input, suggestions } => {
+                write!(f, "no country matches {input:?}")?;
+                if !suggestions.is_empty() {
+                    write!(f, ", did you mean {}?", suggestions.join(" or "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, |country| country.name)
+    }
+}
+
+impl fmt::Display for LookupError<&CountryData> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, |country| &country.name)
+    }
+}
+
+impl Error for LookupError {}
+
+impl Error for LookupError<&CountryData> {}
+
+/// Error returned when a [`Registry`](struct.Registry.html) override can't be
+/// applied.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverrideError {
+    /// No country in the registry has the alpha_2 code
+    UnknownCountry(String),
+    /// The code is not one of the ranges ISO 3166-1 leaves to users, eg. "XK"
+    NotUserAssigned(String),
+    /// The code already belongs to a country in the registry
+    CodeInUse(String),
+    /// Another country in the registry already has the name, or has it as an
+    /// alternative spelling
+    NameInUse(String),
+}
+
+impl fmt::Display for OverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverrideError::UnknownCountry(code) => {
+                write!(f, "no country has the alpha_2 code {code:?}")
+            }
+            OverrideError::NotUserAssigned(code) => {
+                write!(f, "{code:?} is not a user-assigned code")
+            }
+            OverrideError::CodeInUse(code) => {
+                write!(f, "{code:?} is already used by another country")
+            }
+            OverrideError::NameInUse(name) => {
+                write!(f, "{name:?} already names another country")
+            }
+        }
+    }
+}
+
+impl Error for OverrideError {}
+
+/// Error returned by [`Money`](struct.Money.html) arithmetic and parsing.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The currency has no minor unit, like XAU, so can't be counted in one
+    NoMinorUnit(String),
+    /// The amounts are in different currencies, with their codes
+    CurrencyMismatch(String, String),
+    /// The result doesn't fit in an `i64` of minor units
+    Overflow,
+    /// A rate table has no way to convert between the currencies, with their codes
+    NoRate(String, String),
+    /// A rate isn't positive and finite, so can't convert between the currencies
+    InvalidRate {
+        /// Code of the currency converted from
+        from: String,
+        /// Code of the currency converted to
+        to: String,
+        /// The rate, formatted
+        rate: String,
+    },
+    /// The currency has no code to look its rates up by, with its name
+    NoCode(String),
+    /// The amount is not a number, as given
+    InvalidAmount(String),
+    /// The amount has more decimals than its currency's minor unit, as given
+    /// and with the minor unit
+    TooManyDecimals(String, u8),
+    /// No currency has the code or symbol, as given
+    UnknownCurrency(String),
+    /// More than one currency has the symbol, eg. "$"
+    AmbiguousSymbol {
+        /// The symbol as given
+        symbol: String,
+        /// Codes of the currencies with the symbol, sorted
+        candidates: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::NoMinorUnit(code) => write!(f, "{code} has no minor unit"),
+            MoneyError::CurrencyMismatch(left, right) => {
+                write!(f, "can't mix amounts in {left} and {right}")
+            }
+            MoneyError::Overflow => f.write_str("amount out of range"),
+            MoneyError::NoRate(from, to) => write!(f, "no rate from {from} to {to}"),
+            MoneyError::InvalidRate { from, to, rate } => {
+                write!(
+                    f,
+                    "rate {rate} from {from} to {to} is not positive and finite"
+                )
+            }
+            MoneyError::NoCode(name) => write!(f, "currency {name:?} has no code"),
+            MoneyError::InvalidAmount(input) => write!(f, "{input:?} is not an amount"),
+            MoneyError::TooManyDecimals(input, minor_unit) => {
+                write!(f, "{input:?} has more than {minor_unit} decimals")
+            }
+            MoneyError::UnknownCurrency(input) => {
+                write!(f, "no currency has the code or symbol {input:?}")
+            }
+            MoneyError::AmbiguousSymbol { symbol, candidates } => {
+                write!(f, "{symbol:?} is the symbol of {}", candidates.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for MoneyError {}
@@ -0,0 +1,184 @@
+use crate::{Country, HISTORIC_COUNTRIES};
+
+/// Represents a country code withdrawn from ISO 3166-1, as listed in ISO 3166-3.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HistoricCountry {
+    /// Name of the country when its code was withdrawn, eg. "Netherlands Antilles"
+    pub name: &'static str,
+    /// Former ISO 3166-1 alpha_2 code, eg. "AN"
+    pub alpha_2: &'static str,
+    /// Former ISO 3166-1 alpha_3 code, eg. "ANT"
+    pub alpha_3: &'static str,
+    /// ISO 3166-3 alpha_4 code, the former alpha_2 code followed by the new one,
+    /// or "HH" when the country was split up, eg. "ANHH"
+    pub alpha_4: &'static str,
+    /// Former ISO 3166-1 numeric code, if it had one, eg. "530"
+    pub numeric_code: Option<&'static str>,
+    /// When the code was first assigned, as "YYYY-MM-DD" or just "YYYY", eg. "1974"
+    /// for the codes of the first edition of ISO 3166
+    pub introduced: &'static str,
+    /// When the code was withdrawn, as "YYYY-MM-DD" or just "YYYY" for older entries
+    pub withdrawn: &'static str,
+    /// Remarks from ISO 3166-3, eg. about an earlier numeric code
+    pub comment: Option<&'static str>,
+    /// Alpha_2 codes of the current countries that took over the former country's area
+    pub successors: &'static [&'static str],
+}
+
+impl HistoricCountry {
+    /// Get an iterator over all the withdrawn codes, sorted by alpha_4 code
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        HISTORIC_COUNTRIES.iter()
+    }
+    /// Get the historic country from its ISO 3166-3 alpha_4 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::HistoricCountry;
+    ///
+    /// let antilles = HistoricCountry::from_alpha_4("ANHH").unwrap();
+    /// assert_eq!(antilles.name, "Netherlands Antilles");
+    /// ```
+    pub fn from_alpha_4(alpha_4: &str) -> Option<&'static Self> {
+        let index = HISTORIC_COUNTRIES
+            .binary_search_by_key(&alpha_4, |country| country.alpha_4)
+            .ok()?;
+        Some(&HISTORIC_COUNTRIES[index])
+    }
+    /// Get the historic countries that used an alpha_2 code, oldest first
+    ///
+    /// Unlike current codes these are not unique, "CS" was used by both
+    /// Czechoslovakia and Serbia and Montenegro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::HistoricCountry;
+    ///
+    /// let cs = HistoricCountry::from_alpha_2("CS");
+    /// assert_eq!(cs[0].alpha_4, "CSHH");
+    /// assert_eq!(cs[1].alpha_4, "CSXX");
+    /// ```
+    pub fn from_alpha_2(alpha_2: &str) -> Vec<&'static Self> {
+        Self::matching(|country| country.alpha_2 == alpha_2)
+    }
+    /// Get the historic countries that used an alpha_3 code, oldest first
+    pub fn from_alpha_3(alpha_3: &str) -> Vec<&'static Self> {
+        Self::matching(|country| country.alpha_3 == alpha_3)
+    }
+    fn matching(predicate: impl Fn(&Self) -> bool) -> Vec<&'static Self> {
+        let mut countries: Vec<_> = Self::iter().filter(|country| predicate(country)).collect();
+        countries.sort_by_key(|country| country.withdrawn);
+        countries
+    }
+    /// Get the current countries that took over the former country's area
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::HistoricCountry;
+    ///
+    /// let czechoslovakia = HistoricCountry::from_alpha_4("CSHH").unwrap();
+    /// let successors = czechoslovakia.successors();
+    /// assert_eq!(successors[0].alpha_2, "CZ");
+    /// assert_eq!(successors[1].alpha_2, "SK");
+    /// ```
+    pub fn successors(&self) -> Vec<&'static Country> {
+        Country::iter()
+            .filter(|country| self.successors.contains(&country.alpha_2))
+            .collect()
+    }
+    /// Whether the code was in use on a date given as "YYYY-MM-DD"
+    ///
+    /// A code is in use from the date it was introduced until the day before it
+    /// was withdrawn. Dates recorded as just a year cover all of that year, so a
+    /// code withdrawn in 1984 is in use until the end of 1984.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::HistoricCountry;
+    ///
+    /// let ussr = HistoricCountry::from_alpha_4("SUHH").unwrap();
+    /// assert!(ussr.in_use_on("1991-12-26"));
+    /// assert!(!ussr.in_use_on("1992-08-30"));
+    /// // Serbia and Montenegro only had its own code from 2003
+    /// let serbia_and_montenegro = HistoricCountry::from_alpha_4("CSXX").unwrap();
+    /// assert!(!serbia_and_montenegro.in_use_on("1980-01-01"));
+    /// assert!(serbia_and_montenegro.in_use_on("2004-01-01"));
+    /// ```
+    pub fn in_use_on(&self, date: &str) -> bool {
+        let introduced = match self.introduced.len() {
+            4 => date.get(..4).is_some_and(|year| year >= self.introduced),
+            _ => date >= self.introduced,
+        };
+        let withdrawn = match self.withdrawn.len() {
+            4 => date.get(..4).is_some_and(|year| year <= self.withdrawn),
+            _ => date < self.withdrawn,
+        };
+        introduced && withdrawn
+    }
+    /// Get the withdrawn codes that were still in use on a date given as "YYYY-MM-DD"
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::HistoricCountry;
+    ///
+    /// let in_1995 = HistoricCountry::in_use("1995-01-01");
+    /// assert!(in_1995.iter().any(|country| country.alpha_4 == "YUCS"));
+    /// assert!(!in_1995.iter().any(|country| country.alpha_4 == "SUHH"));
+    /// let in_1980 = HistoricCountry::in_use("1980-01-01");
+    /// assert!(!in_1980.iter().any(|country| country.alpha_4 == "CSXX"));
+    /// ```
+    pub fn in_use(date: &str) -> Vec<&'static Self> {
+        Self::iter()
+            .filter(|country| country.in_use_on(date))
+            .collect()
+    }
+}
+
+impl Country {
+    /// Get the current countries that succeeded a withdrawn alpha_2, alpha_3 or
+    /// alpha_4 code, sorted by alpha_2 code
+    ///
+    /// A code that is assigned today resolves to its current country alone, even
+    /// if a former country used it, so "SK" is Slovakia rather than Sikkim,
+    /// whose alpha_4 code "SKIN" still resolves to India. A retired code used by
+    /// more than one former country resolves to the successors of all of them,
+    /// so "CS" gives both the Czech Republic and Serbia.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let antilles = Country::resolve_historic("AN");
+    /// let codes: Vec<_> = antilles.iter().map(|country| country.alpha_2).collect();
+    /// assert_eq!(codes, ["BQ", "CW", "SX"]);
+    /// assert_eq!(Country::resolve_historic("SK")[0].name, "Slovakia");
+    /// ```
+    pub fn resolve_historic(code: &str) -> Vec<&'static Country> {
+        let current = Country::iter().find(|country| match code.len() {
+            2 => country.alpha_2 == code,
+            _ => country.alpha_3 == code,
+        });
+        if let Some(country) = current {
+            return vec![country];
+        }
+        let historic: Vec<_> = HistoricCountry::iter()
+            .filter(|country| match code.len() {
+                4 => country.alpha_4 == code,
+                _ => country.alpha_2 == code || country.alpha_3 == code,
+            })
+            .collect();
+        Country::iter()
+            .filter(|country| {
+                historic
+                    .iter()
+                    .any(|historic| historic.successors.contains(&country.alpha_2))
+            })
+            .collect()
+    }
+}
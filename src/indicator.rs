@@ -0,0 +1,101 @@
+use crate::Country;
+
+/// A numeric socio-economic indicator carried by [`Country`](struct.Country.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Indicator {
+    /// Gini coefficient of income inequality, from 0 (perfect equality) to 100.
+    #[cfg(feature = "gini")]
+    Gini,
+}
+
+/// Summary statistics of an [`Indicator`](enum.Indicator.html) over a set of countries.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    /// Number of countries that have a value for the indicator
+    pub count: usize,
+    /// Lowest value
+    pub min: f64,
+    /// Highest value
+    pub max: f64,
+    /// Median value, the mean of the two middle values for an even count
+    pub median: f64,
+}
+
+impl Indicator {
+    /// Summarize the indicator over a set of countries, skipping the ones without a
+    /// value. Returns `None` if none of them have one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// # #[cfg(feature = "gini")] {
+    /// let countries = ["Sweden", "Norway", "India"].map(|name| Country::from_name(name).unwrap());
+    /// let summary = Indicator::Gini.summary(countries).unwrap();
+    /// assert_eq!(summary.count, 3);
+    /// assert!(summary.min <= summary.median && summary.median <= summary.max);
+    /// # }
+    /// ```
+    pub fn summary<'a>(self, countries: impl IntoIterator<Item = &'a Country>) -> Option<Summary> {
+        let mut values = countries
+            .into_iter()
+            .filter_map(|country| country.indicator(self))
+            .collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+        let count = values.len();
+        let median = match count {
+            0 => return None,
+            _ if count % 2 == 0 => (values[count / 2 - 1] + values[count / 2]) / 2.0,
+            _ => values[count / 2],
+        };
+        Some(Summary {
+            count,
+            min: values[0],
+            max: values[count - 1],
+            median,
+        })
+    }
+}
+
+impl Country {
+    /// Get the value of an indicator for the country, if the data has one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// # #[cfg(feature = "gini")] {
+    /// let country = Country::from_name("India").unwrap();
+    /// assert_eq!(country.indicator(Indicator::Gini), Some(35.7));
+    /// # }
+    /// ```
+    pub fn indicator(&self, indicator: Indicator) -> Option<f64> {
+        match indicator {
+            #[cfg(feature = "gini")]
+            // Widening an f32 shows its binary error, eg. 35.7 becomes
+            // 35.70000076..., so round it to four decimals, more than the data has
+            Indicator::Gini => self
+                .gini
+                .map(|gini| (f64::from(gini) * 10_000.0).round() / 10_000.0),
+        }
+    }
+    /// Summarize an indicator over the countries of a region
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// # #[cfg(feature = "gini")] {
+    /// let europe = Country::region_summary(Region::Europe, Indicator::Gini).unwrap();
+    /// assert!(europe.min < europe.max);
+    /// # }
+    /// ```
+    #[cfg(feature = "from_regions")]
+    pub fn region_summary(region: crate::Region, indicator: Indicator) -> Option<Summary> {
+        indicator.summary(Self::from_region(region.as_str())?)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::DATA_INFO;
+
+/// Which data the crate was built with, see [`data_info`](fn.data_info.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DataInfo {
+    /// Version of the IANA tz database the timezones were taken from, eg. "2026c"
+    pub tzdata_version: Option<&'static str>,
+    /// Where the timezones were downloaded from
+    pub tzdata_source: Option<&'static str>,
+    /// Number of country timezones in the timezone data
+    pub timezones: usize,
+    /// FNV-1a hash of the countries JSON, as 16 hex digits. Two builds with the
+    /// same hash used the same country data.
+    pub countries_hash: &'static str,
+    /// Where the countries JSON came from, or just its file name if it was
+    /// overridden with `ISO_RS_COUNTRIES_PATH`, so the build stays reproducible
+    pub countries_source: Option<&'static str>,
+    /// Date the countries JSON was fetched, eg. "2026-10-19", if known, which
+    /// it isn't for an override
+    pub countries_date: Option<&'static str>,
+    /// Number of records in the countries JSON
+    pub country_records: usize,
+    /// Number of countries built from those records, see
+    /// [`Country::iter`](struct.Country.html#method.iter)
+    pub countries: usize,
+}
+
+/// Get the versions, sources and sizes of the data the crate was built with,
+/// eg. to tell which data a deployed binary has.
+///
+/// # Example
+///
+/// ```
+/// let info = iso_rs::data_info();
+/// assert_eq!(info.countries_hash.len(), 16);
+/// assert!(info.countries <= info.country_records);
+/// println!("tzdata {}", info.tzdata_version.unwrap_or("unknown"));
+/// ```
+pub fn data_info() -> DataInfo {
+    DATA_INFO
+}
@@ -0,0 +1,87 @@
+use crate::{LANGUAGES, Language};
+
+impl Language {
+    /// Get an iterator over all the ISO 639-3 languages, sorted by ISO 639-3 code
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        LANGUAGES.iter()
+    }
+    /// Get the language from its ISO 639-3 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{LanguageScope, LanguageType, prelude::*};
+    ///
+    /// let mandarin = Language::from_iso639_3("cmn").unwrap();
+    /// assert_eq!(mandarin.name, Some("Mandarin Chinese"));
+    /// assert_eq!(mandarin.scope, Some(LanguageScope::Individual));
+    /// assert_eq!(mandarin.language_type, Some(LanguageType::Living));
+    /// assert_eq!(mandarin.macrolanguage().unwrap().iso639_1, Some("zh"));
+    /// ```
+    pub fn from_iso639_3(code: &str) -> Option<&'static Self> {
+        let index = LANGUAGES
+            .binary_search_by_key(&Some(code), |language| language.iso639_3)
+            .ok()?;
+        Some(&LANGUAGES[index])
+    }
+    /// Get the language from its ISO 639-2 code, either the terminology or the
+    /// bibliographic one
+    ///
+    /// Only the ISO 639-2 codes that ISO 639-3 shares are known, so collective
+    /// codes for language families, like "sla" for the Slavic languages, give
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let french = Language::from_iso639_2("fre").unwrap();
+    /// assert_eq!(french.iso639_2, Some("fra"));
+    /// assert_eq!(Language::from_iso639_2("fra"), Some(french));
+    /// ```
+    pub fn from_iso639_2(code: &str) -> Option<&'static Self> {
+        Self::from_iso639_3(code)
+            .filter(|language| language.iso639_2.is_some())
+            .or_else(|| Self::iter().find(|language| language.iso639_2b == Some(code)))
+    }
+    /// Get the language from its ISO 639-1 code
+    pub fn from_iso639_1(code: &str) -> Option<&'static Self> {
+        Self::iter().find(|language| language.iso639_1 == Some(code))
+    }
+    /// Get the language from an ISO 639-1, 639-2 or 639-3 code
+    pub fn from_code(code: &str) -> Option<&'static Self> {
+        match code.len() {
+            2 => Self::from_iso639_1(code),
+            _ => Self::from_iso639_3(code).or_else(|| Self::from_iso639_2(code)),
+        }
+    }
+    /// Get the language from its english name, eg. "Swahili"
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::iter().find(|language| language.name == Some(name))
+    }
+    /// Get the macrolanguage an individual language belongs to, if any
+    pub fn macrolanguage(&self) -> Option<&'static Self> {
+        Self::from_iso639_3(self.macrolanguage?)
+    }
+    /// Get the individual languages of a macrolanguage, sorted by ISO 639-3 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let norwegian = Language::from_iso639_1("no").unwrap();
+    /// let members = norwegian.members();
+    /// assert_eq!(members[0].name, Some("Norwegian Nynorsk"));
+    /// assert_eq!(members[1].name, Some("Norwegian Bokmål"));
+    /// ```
+    pub fn members(&self) -> Vec<&'static Self> {
+        let Some(code) = self.iso639_3 else {
+            return Vec::new();
+        };
+        Self::iter()
+            .filter(|language| language.macrolanguage == Some(code))
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Scope of an ISO 639-3 code, as found in `Language::scope`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LanguageScope {
+    /// "Individual", a single language, eg. French
+    Individual,
+    /// "Macrolanguage", closely related languages that can be taken as one,
+    /// eg. Arabic
+    Macrolanguage,
+    /// "Special", codes that aren't languages, eg. "und" for undetermined
+    Special,
+}
+
+impl LanguageScope {
+    /// All the scopes
+    pub const ALL: [LanguageScope; 3] = [
+        LanguageScope::Individual,
+        LanguageScope::Macrolanguage,
+        LanguageScope::Special,
+    ];
+
+    /// Get the scope from its one letter code in the ISO 639-3 tables, eg. "M"
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str().get(..1) == Some(code))
+    }
+
+    /// Name of the scope, eg. "Macrolanguage"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageScope::Individual => "Individual",
+            LanguageScope::Macrolanguage => "Macrolanguage",
+            LanguageScope::Special => "Special",
+        }
+    }
+}
+
+impl fmt::Display for LanguageScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Type of an ISO 639-3 language, as found in `Language::language_type`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LanguageType {
+    /// "Living", eg. French
+    Living,
+    /// "Extinct" in recent times, eg. Dalmatian
+    Extinct,
+    /// "Ancient", extinct for over a millennium, eg. Latin
+    Ancient,
+    /// "Historical", an earlier form of a living language, eg. Old English
+    Historical,
+    /// "Constructed", eg. Esperanto
+    Constructed,
+    /// "Special", codes that aren't languages, eg. "mul" for multiple languages
+    Special,
+}
+
+impl LanguageType {
+    /// All the types
+    pub const ALL: [LanguageType; 6] = [
+        LanguageType::Living,
+        LanguageType::Extinct,
+        LanguageType::Ancient,
+        LanguageType::Historical,
+        LanguageType::Constructed,
+        LanguageType::Special,
+    ];
+
+    /// Get the type from its one letter code in the ISO 639-3 tables, eg. "L"
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language_type| language_type.as_str().get(..1) == Some(code))
+    }
+
+    /// Name of the type, eg. "Living"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageType::Living => "Living",
+            LanguageType::Extinct => "Extinct",
+            LanguageType::Ancient => "Ancient",
+            LanguageType::Historical => "Historical",
+            LanguageType::Constructed => "Constructed",
+            LanguageType::Special => "Special",
+        }
+    }
+}
+
+impl fmt::Display for LanguageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
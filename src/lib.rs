@@ -19,10 +19,36 @@
 //! - `from_alpha_3`: Allows you to query country data by alpha_3 codes.
 //! - `from_regions`: Allows you to query country data by their regions.
 //! - `from_subregions`: Allows you to query country data by their subregions.
+//! - `from_ioc_codes`: Allows you to query country data by IOC (Olympic) codes.
+//!
+//! Optional fields of [`Country`](struct.Country.html) have features of their
+//! own, and are left out of the generated data altogether when turned off:
+//!
+//! - `timezones`, `currencies`, `languages`, `call_codes`
+//! - `numeric_codes`, `alt_spellings`, `ioc_codes`, `gini`
+//! - `sovereignty`: whether a country is independent, and its sovereign state
+//! - `flags`: flag emoji and image URLs
+//!
+//! The `fields` feature turns on all of them.
 //!
 //! By default all these features are enabled. It is recommended to
 //! turn off the features you will not be using as the country data is
-//! high in number and you'll be saving some static allocation.
+//! high in number and you'll be saving some static allocation. With
+//! `default-features = false` a country is just its name, capital, region,
+//! subregion and alpha codes.
+//!
+//! The `runtime` feature, off by default, adds [`Dataset`](struct.Dataset.html)
+//! for loading other country data from JSON at runtime.
+//!
+//! These features are also off by default and left out of `all`, as they
+//! compile in data of the iso-codes project, which is LGPL-2.1-or-later rather
+//! than MIT. `iso_codes` turns them all on.
+//!
+//! - `subdivisions`: ISO 3166-2 subdivisions of countries, like states and provinces.
+//! - `historic`: ISO 3166-3 withdrawn countries as [`HistoricCountry`](struct.HistoricCountry.html).
+//! - `iso4217`: the ISO 4217 names and numeric codes of currencies.
+//! - `iso639`: all the ISO 639-3 languages, with lookups on [`Language`](struct.Language.html).
+//! - `iso15924`: all the ISO 15924 scripts as [`Script`](struct.Script.html).
 //!
 //! # Example
 //!
@@ -33,15 +59,78 @@
 //! assert_eq!(country.capital.unwrap(), "New Delhi");
 //! ```
 //!
+#[cfg(feature = "timezones")]
 use chrono_tz::{ParseError, Tz};
 
-/// Prelude brings the `Country`, `Currency` and `Language` structs in scope.
+// Shared with the build script. The builder is only used by the runtime parser
+// and to assign countries in a registry.
+#[cfg_attr(not(feature = "runtime"), allow(dead_code))]
+#[path = "../build/countries/country_data.rs"]
+mod country_data;
+#[cfg(feature = "currencies")]
+mod currency;
+mod currency_kind;
+#[cfg(feature = "runtime")]
+mod dataset;
+mod error;
+#[cfg(feature = "historic")]
+mod historic;
+mod indicator;
+mod info;
+#[cfg(feature = "iso639")]
+mod language;
+mod language_kind;
+mod lookup;
+#[cfg(feature = "currencies")]
+mod money;
+// Shared with the build script, so both read the data the same way
+#[cfg(feature = "runtime")]
+#[path = "../build/countries/parse.rs"]
+mod parse;
+mod query;
+#[cfg(feature = "currencies")]
+mod rates;
+mod records;
+mod region;
+mod registry;
+#[cfg(feature = "iso15924")]
+mod script;
+mod status;
+#[cfg(feature = "subdivisions")]
+mod subdivision;
+
+pub use country_data::{CountryData, CurrencyData, LanguageData, TimezoneData};
+pub use currency_kind::CurrencyKind;
+#[cfg(feature = "runtime")]
+pub use dataset::{Dataset, SkippedRecord};
+pub use error::{LookupError, MoneyError, OverrideError};
+#[cfg(feature = "historic")]
+pub use historic::HistoricCountry;
+pub use indicator::{Indicator, Summary};
+pub use info::{DataInfo, data_info};
+pub use language_kind::{LanguageScope, LanguageType};
+pub use lookup::{Lookup, MatchKind};
+#[cfg(feature = "currencies")]
+pub use money::Money;
+pub use query::Query;
+#[cfg(feature = "currencies")]
+pub use rates::{MemoryRateTable, RateTable};
+pub use region::Region;
+pub use registry::Registry;
+#[cfg(feature = "iso15924")]
+pub use script::Script;
+pub use status::{CodeStatus, ReservedCode, classify_alpha_2};
+#[cfg(feature = "subdivisions")]
+pub use subdivision::Subdivision;
+
+/// Prelude brings the `Country`, `Currency` and `Language` structs and the
+/// `Indicator` and `Region` enums in scope.
 pub mod prelude {
-    pub use crate::{Country, Currency, Language};
+    pub use crate::{Country, Currency, Indicator, Language, Region};
 }
 
 /// Represents a Country.
-#[derive(Copy, Debug, Clone, PartialEq, Eq)]
+#[derive(Copy, Debug, Clone)]
 pub struct Country {
     /// Name of the country, eg. "United States".
     pub name: &'static str,
@@ -56,16 +145,151 @@ pub struct Country {
     /// 3166-1 3-letter country code
     pub alpha_3: &'static str,
     /// Timezones that country has in UTC, eg. `UTC-05:00` for columbia
+    #[cfg(feature = "timezones")]
     pub timezones: &'static [Timezone],
     /// Currencies used in the country
+    #[cfg(feature = "currencies")]
     pub currencies: &'static [Currency],
     /// Languages used in the country
+    #[cfg(feature = "languages")]
     pub languages: &'static [Language],
     /// Dialling codes used in a country
+    #[cfg(feature = "call_codes")]
     pub call_codes: &'static [&'static str],
+    /// ISO 3166-1 numeric code, eg. "356" for India
+    #[cfg(feature = "numeric_codes")]
+    pub numeric_code: Option<&'static str>,
+    /// Alternative spellings of the country's name, eg. "Bhārat" for India
+    #[cfg(feature = "alt_spellings")]
+    pub alt_spellings: &'static [&'static str],
+    // Whether the country is a sovereign state, see `is_independent`
+    #[cfg(feature = "sovereignty")]
+    independent: bool,
+    // ISO 3166-1 alpha_2 code of the sovereign state, see `sovereign`
+    #[cfg(feature = "sovereignty")]
+    sovereign: Option<&'static str>,
+    // Regional indicator pair for the alpha_2 code, see `flag_emoji`
+    #[cfg(feature = "flags")]
+    flag_emoji: &'static str,
+    /// URL of the country's flag as an SVG image
+    #[cfg(feature = "flags")]
+    pub flag_svg: Option<&'static str>,
+    /// URL of the country's flag as a 320px wide PNG image
+    #[cfg(feature = "flags")]
+    pub flag_png: Option<&'static str>,
+    /// International Olympic Committee code, eg. "GER" for Germany
+    #[cfg(feature = "ioc_codes")]
+    pub ioc_code: Option<&'static str>,
+    /// Gini coefficient of income inequality, from 0 to 100
+    #[cfg(feature = "gini")]
+    pub gini: Option<f32>,
+}
+
+// Compares gini by its bits, so that equality stays reflexive and `Country`
+// can be `Eq` even with a float field
+impl PartialEq for Country {
+    fn eq(&self, other: &Self) -> bool {
+        let Country {
+            name,
+            capital,
+            region,
+            subregion,
+            alpha_2,
+            alpha_3,
+            #[cfg(feature = "timezones")]
+            timezones,
+            #[cfg(feature = "currencies")]
+            currencies,
+            #[cfg(feature = "languages")]
+            languages,
+            #[cfg(feature = "call_codes")]
+            call_codes,
+            #[cfg(feature = "numeric_codes")]
+            numeric_code,
+            #[cfg(feature = "alt_spellings")]
+            alt_spellings,
+            #[cfg(feature = "sovereignty")]
+            independent,
+            #[cfg(feature = "sovereignty")]
+            sovereign,
+            #[cfg(feature = "flags")]
+            flag_emoji,
+            #[cfg(feature = "flags")]
+            flag_svg,
+            #[cfg(feature = "flags")]
+            flag_png,
+            #[cfg(feature = "ioc_codes")]
+            ioc_code,
+            #[cfg(feature = "gini")]
+            gini,
+        } = self;
+        #[cfg(feature = "timezones")]
+        if *timezones != other.timezones {
+            return false;
+        }
+        #[cfg(feature = "currencies")]
+        if *currencies != other.currencies {
+            return false;
+        }
+        #[cfg(feature = "languages")]
+        if *languages != other.languages {
+            return false;
+        }
+        #[cfg(feature = "call_codes")]
+        if *call_codes != other.call_codes {
+            return false;
+        }
+        #[cfg(feature = "numeric_codes")]
+        if *numeric_code != other.numeric_code {
+            return false;
+        }
+        #[cfg(feature = "alt_spellings")]
+        if *alt_spellings != other.alt_spellings {
+            return false;
+        }
+        #[cfg(feature = "sovereignty")]
+        if *independent != other.independent || *sovereign != other.sovereign {
+            return false;
+        }
+        #[cfg(feature = "flags")]
+        if *flag_emoji != other.flag_emoji
+            || *flag_svg != other.flag_svg
+            || *flag_png != other.flag_png
+        {
+            return false;
+        }
+        #[cfg(feature = "ioc_codes")]
+        if *ioc_code != other.ioc_code {
+            return false;
+        }
+        #[cfg(feature = "gini")]
+        if gini.map(f32::to_bits) != other.gini.map(f32::to_bits) {
+            return false;
+        }
+        *name == other.name
+            && *capital == other.capital
+            && *region == other.region
+            && *subregion == other.subregion
+            && *alpha_2 == other.alpha_2
+            && *alpha_3 == other.alpha_3
+    }
+}
+
+impl Eq for Country {}
+
+// Equal countries have the same alpha_2 code, so hashing it alone agrees
+// with `Eq`
+impl std::hash::Hash for Country {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.alpha_2.hash(state);
+    }
 }
 
 /// Represents a Currency with ISO 4217 code.
+///
+/// The numeric code, minor unit and kind come from ISO 4217 and are `None` for
+/// currencies missing from it, such as the Faroese króna. The numeric code is
+/// only filled in with the `iso4217` feature.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct Currency {
     /// ISO 4217 currency code
@@ -74,22 +298,64 @@ pub struct Currency {
     pub name: Option<&'static str>,
     /// Symbol of the currency
     pub symbol: Option<&'static str>,
+    /// ISO 4217 numeric code, eg. "978" for EUR, with the `iso4217` feature
+    pub numeric_code: Option<&'static str>,
+    /// Number of digits after the decimal point, eg. 2 for EUR, 0 for JPY and 3
+    /// for BHD, `None` for codes that are not money, like XAU
+    pub minor_unit: Option<u8>,
+    /// What the code stands for, eg. a fund or a precious metal
+    pub kind: Option<CurrencyKind>,
+    /// When the currency was introduced, if known, eg. "1999-01-01" for EUR
+    pub introduced: Option<&'static str>,
+    /// When ISO 4217 withdrew the code, as "YYYY-MM" like its list of historic
+    /// denominations, eg. "2002-03" for DEM
+    pub withdrawn: Option<&'static str>,
+    /// Code of the currency that replaced this one, eg. "EUR" for DEM
+    pub replaced_by: Option<&'static str>,
+    /// Units of this currency per unit of the one that replaced it, where that
+    /// was fixed, as a decimal string so it stays exact, eg. "1.95583" for DEM
+    pub conversion_rate: Option<&'static str>,
+    /// Smallest amount paid in cash, in minor units, where coins for the minor
+    /// unit aren't in use, eg. 5 for CHF, see [`cash_increment`](#method.cash_increment)
+    pub cash_increment: Option<u32>,
+    /// Symbol telling the currency apart from others sharing its symbol, eg.
+    /// "US$", see [`narrow_symbol`](#method.narrow_symbol)
+    pub narrow_symbol: Option<&'static str>,
 }
 
 /// Represents a Language with both ISO 639-1 and ISO 639-2 codes.
+///
+/// The bibliographic, ISO 639-3, scope, type and macrolanguage fields come from
+/// ISO 639-3, and are `None` for languages missing from it. The script is only
+/// set for common languages.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct Language {
     /// ISO 639-1 language code
     pub iso639_1: Option<&'static str>,
-    /// ISO 639-2 language code
+    /// ISO 639-2 terminology code, eg. "fra"
     pub iso639_2: Option<&'static str>,
     /// Name of the language in english
     pub name: Option<&'static str>,
     /// Native name of the language, can be in the native language
     pub native_name: Option<&'static str>,
+    /// ISO 639-2 bibliographic code, where it differs from the terminology one,
+    /// eg. "fre"
+    pub iso639_2b: Option<&'static str>,
+    /// ISO 639-3 code, eg. "fra"
+    pub iso639_3: Option<&'static str>,
+    /// Whether the code is for a single language or a macrolanguage
+    pub scope: Option<LanguageScope>,
+    /// Whether the language is living, extinct, constructed, etc.
+    pub language_type: Option<LanguageType>,
+    /// ISO 639-3 code of the macrolanguage the language belongs to, eg. "zho"
+    /// for Mandarin Chinese
+    pub macrolanguage: Option<&'static str>,
+    /// ISO 15924 code of the script the language is usually written in, eg. "Latn"
+    pub script: Option<&'static str>,
 }
 
 /// Represents a timezone with offset (UTC) and the IANA identifier
+#[cfg(feature = "timezones")]
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub struct Timezone {
     /// IANA identifier for the timezone. For eg. Asia/Kolkata
@@ -100,6 +366,32 @@ pub struct Timezone {
 include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
 
 impl Country {
+    /// Get an iterator over all the countries, sorted by alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let first = Country::iter().next().unwrap();
+    /// assert_eq!(first.alpha_2, "AD");
+    /// ```
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        COUNTRIES.iter()
+    }
+    /// Start a [`Query`](struct.Query.html) over all the countries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let southern_asia = Country::query().subregion("Southern Asia").collect();
+    /// assert!(southern_asia.contains(&Country::from_name("India").unwrap()));
+    /// ```
+    pub fn query<'a>() -> Query<'a> {
+        Query::new()
+    }
     /// Get the country from it's name
     ///
     /// # Example
@@ -162,12 +454,21 @@ impl Country {
     /// ```
     /// use iso_rs::prelude::*;
     ///
-    /// let mut country = Country::from_alpha_2("IN").unwrap();
-    /// assert_eq!(country[0], *Country::from_name("India").unwrap());
+    /// let country = Country::from_alpha_2("IN").unwrap();
+    /// assert_eq!(country, Country::from_name("India").unwrap());
     /// ```
     #[cfg(feature = "from_alpha_2")]
-    pub fn from_alpha_2(alpha_2: &str) -> Option<&'static [Self]> {
-        ALPHA_2.get(alpha_2).copied()
+    pub fn from_alpha_2(alpha_2: &str) -> Option<&'static Self> {
+        ALPHA_2.get(alpha_2)
+    }
+    /// Get the country from its ISO 3166-1 alpha_2 code, as a slice of one
+    #[cfg(feature = "from_alpha_2")]
+    #[deprecated(
+        since = "0.3.0",
+        note = "alpha_2 codes are unique, use `from_alpha_2` which returns the country itself"
+    )]
+    pub fn from_alpha_2_slice(alpha_2: &str) -> Option<&'static [Self]> {
+        Self::from_alpha_2(alpha_2).map(std::slice::from_ref)
     }
     /// Get the country from its ISO 3166-1 alpha_3 code
     ///
@@ -176,15 +477,132 @@ impl Country {
     /// ```
     /// use iso_rs::prelude::*;
     ///
-    /// let mut country = Country::from_alpha_3("IND").unwrap();
-    /// assert_eq!(country[0], *Country::from_name("India").unwrap());
+    /// let country = Country::from_alpha_3("IND").unwrap();
+    /// assert_eq!(country, Country::from_name("India").unwrap());
     /// ```
     #[cfg(feature = "from_alpha_3")]
-    pub fn from_alpha_3(alpha_3: &str) -> Option<&'static [Self]> {
-        ALPHA_3.get(alpha_3).copied()
+    pub fn from_alpha_3(alpha_3: &str) -> Option<&'static Self> {
+        ALPHA_3.get(alpha_3)
+    }
+    /// Get the country from its ISO 3166-1 alpha_3 code, as a slice of one
+    #[cfg(feature = "from_alpha_3")]
+    #[deprecated(
+        since = "0.3.0",
+        note = "alpha_3 codes are unique, use `from_alpha_3` which returns the country itself"
+    )]
+    pub fn from_alpha_3_slice(alpha_3: &str) -> Option<&'static [Self]> {
+        Self::from_alpha_3(alpha_3).map(std::slice::from_ref)
+    }
+    /// Get the country from its IOC (Olympic) code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let country = Country::from_ioc_code("GER").unwrap();
+    /// assert_eq!(country.alpha_3, "DEU");
+    /// ```
+    #[cfg(feature = "from_ioc_codes")]
+    pub fn from_ioc_code(ioc_code: &str) -> Option<&'static Self> {
+        IOC_CODES.get(ioc_code)
+    }
+    /// Get the country from its flag emoji
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let country = Country::from_flag_emoji("🇮🇳").unwrap();
+    /// assert_eq!(country.name, "India");
+    /// ```
+    #[cfg(feature = "flags")]
+    pub fn from_flag_emoji(flag_emoji: &str) -> Option<&'static Self> {
+        COUNTRIES
+            .iter()
+            .find(|country| country.flag_emoji == flag_emoji)
+    }
+    /// Get the country's flag as an emoji, made of the regional indicator symbols
+    /// for its alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let country = Country::from_name("India").unwrap();
+    /// assert_eq!(country.flag_emoji(), "🇮🇳");
+    /// ```
+    #[cfg(feature = "flags")]
+    pub fn flag_emoji(&self) -> &'static str {
+        self.flag_emoji
+    }
+    /// Get an iterator over all the independent countries, sorted by alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let guam = Country::from_name("Guam").unwrap();
+    /// assert!(!Country::independent_countries().any(|country| country == guam));
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn independent_countries() -> impl Iterator<Item = &'static Self> {
+        COUNTRIES.iter().filter(|country| country.is_independent())
+    }
+    /// Whether the country is a sovereign state rather than a dependent territory
+    /// or an area without a government of its own, like Antarctica.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// assert!(Country::from_name("France").unwrap().is_independent());
+    /// assert!(!Country::from_name("Greenland").unwrap().is_independent());
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn is_independent(&self) -> bool {
+        self.independent
+    }
+    /// Get the sovereign state a dependent territory belongs to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let guam = Country::from_name("Guam").unwrap();
+    /// assert_eq!(guam.sovereign().unwrap().name, "United States of America");
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn sovereign(&self) -> Option<&'static Self> {
+        let sovereign = self.sovereign?;
+        let index = COUNTRIES
+            .binary_search_by_key(&sovereign, |country| country.alpha_2)
+            .ok()?;
+        Some(&COUNTRIES[index])
+    }
+    /// Get the dependent territories of a sovereign state, sorted by alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let denmark = Country::from_name("Denmark").unwrap();
+    /// let territories = denmark.territories();
+    /// assert!(territories.contains(&Country::from_name("Greenland").unwrap()));
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn territories(&self) -> Vec<&'static Self> {
+        Self::query().sovereign(self.alpha_2).collect()
     }
 }
 
+#[cfg(feature = "timezones")]
 impl Timezone {
     /// Get chrono_tz [timezone](https://docs.rs/chrono-tz/latest/chrono_tz/enum.Tz.html)
     pub fn timezone(&self) -> Result<Tz, ParseError> {
@@ -197,21 +615,51 @@ mod test {
     use super::*;
 
     fn india_check(india: &Country) {
+        // Joined to the ISO 639-3 registry only with the iso-codes data
+        #[cfg(feature = "languages")]
+        let iso639 = cfg!(feature = "iso639");
         assert_eq!(india.capital.unwrap(), "New Delhi");
         assert_eq!(india.region.unwrap(), "Asia");
         assert_eq!(india.subregion.unwrap(), "Southern Asia");
         assert_eq!(india.alpha_2, "IN");
         assert_eq!(india.alpha_3, "IND");
+        #[cfg(feature = "timezones")]
         assert_eq!(india.timezones[0].iana_identifier, "Asia/Kolkata");
+        #[cfg(feature = "call_codes")]
         assert_eq!(india.call_codes[0], "91");
+        #[cfg(feature = "numeric_codes")]
+        assert_eq!(india.numeric_code.unwrap(), "356");
+        #[cfg(feature = "alt_spellings")]
+        assert!(india.alt_spellings.contains(&"Bhārat"));
+        #[cfg(feature = "flags")]
+        assert_eq!(india.flag_emoji(), "🇮🇳");
+        #[cfg(feature = "ioc_codes")]
+        assert_eq!(india.ioc_code.unwrap(), "IND");
+        #[cfg(feature = "gini")]
+        assert_eq!(india.gini.unwrap(), 35.7);
+        #[cfg(feature = "flags")]
+        assert_eq!(india.flag_svg.unwrap(), "https://flagcdn.com/in.svg");
+        #[cfg(feature = "flags")]
+        assert_eq!(india.flag_png.unwrap(), "https://flagcdn.com/w320/in.png");
+        #[cfg(feature = "currencies")]
         assert_eq!(
             india.currencies[0],
             Currency {
                 code: Some("INR"),
                 name: Some("Indian rupee"),
                 symbol: Some("₹"),
+                numeric_code: cfg!(feature = "iso4217").then_some("356"),
+                minor_unit: Some(2),
+                kind: Some(CurrencyKind::LegalTender),
+                introduced: None,
+                withdrawn: None,
+                replaced_by: None,
+                conversion_rate: None,
+                cash_increment: None,
+                narrow_symbol: None,
             }
         );
+        #[cfg(feature = "languages")]
         assert_eq!(
             india.languages[0],
             Language {
@@ -219,8 +667,15 @@ mod test {
                 iso639_2: Some("hin"),
                 name: Some("Hindi"),
                 native_name: Some("हिन्दी"),
+                iso639_2b: None,
+                iso639_3: iso639.then_some("hin"),
+                scope: iso639.then_some(LanguageScope::Individual),
+                language_type: iso639.then_some(LanguageType::Living),
+                macrolanguage: None,
+                script: iso639.then_some("Deva"),
             }
         );
+        #[cfg(feature = "languages")]
         assert_eq!(
             india.languages[1],
             Language {
@@ -228,6 +683,12 @@ mod test {
                 iso639_2: Some("eng"),
                 name: Some("English"),
                 native_name: Some("English"),
+                iso639_2b: None,
+                iso639_3: iso639.then_some("eng"),
+                scope: iso639.then_some(LanguageScope::Individual),
+                language_type: iso639.then_some(LanguageType::Living),
+                macrolanguage: None,
+                script: iso639.then_some("Latn"),
             }
         );
     }
@@ -248,15 +709,21 @@ mod test {
     #[cfg(feature = "from_alpha_2")]
     #[test]
     fn basic_country_fetching_from_alpha_2() {
-        let india = Country::from_alpha_2("IN").unwrap()[0];
-        india_check(&india);
+        let india = Country::from_alpha_2("IN").unwrap();
+        india_check(india);
+        #[allow(deprecated)]
+        let slice = Country::from_alpha_2_slice("IN").unwrap();
+        assert_eq!(slice, [*india]);
     }
 
     #[cfg(feature = "from_alpha_3")]
     #[test]
     fn basic_country_fetching_from_alpha_3() {
-        let india = Country::from_alpha_3("IND").unwrap()[0];
-        india_check(&india);
+        let india = Country::from_alpha_3("IND").unwrap();
+        india_check(india);
+        #[allow(deprecated)]
+        let slice = Country::from_alpha_3_slice("IND").unwrap();
+        assert_eq!(slice, [*india]);
     }
 
     #[cfg(feature = "from_regions")]
@@ -266,10 +733,1179 @@ mod test {
         assert!(asia.contains(Country::from_name("India").unwrap()));
     }
 
+    #[test]
+    fn countries_are_hashable() {
+        let countries = Country::iter()
+            .copied()
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(countries.len(), Country::iter().count());
+        let india = *Country::iter().find(|c| c.alpha_2 == "IN").unwrap();
+        assert!(countries.contains(&india));
+        assert_eq!(india, india);
+    }
+
+    #[cfg(feature = "from_ioc_codes")]
+    #[test]
+    fn basic_country_fetching_from_ioc_code() {
+        let india = Country::from_ioc_code("IND").unwrap();
+        india_check(india);
+        assert_eq!(Country::from_ioc_code("NED").unwrap().alpha_3, "NLD");
+        // No IOC code in the data, which is "" rather than null upstream
+        assert_eq!(Country::from_name("Niue").unwrap().ioc_code, None);
+        assert_eq!(Country::from_ioc_code(""), None);
+    }
+
+    #[test]
+    fn iterate_all_countries() {
+        let countries = Country::iter().collect::<Vec<_>>();
+        assert_eq!(countries.len(), NAMES.len());
+        assert!(
+            countries
+                .windows(2)
+                .all(|pair| pair[0].alpha_2 < pair[1].alpha_2)
+        );
+        for country in countries {
+            assert_eq!(Country::from_name(country.name), Some(country));
+        }
+    }
+
+    #[cfg(all(
+        feature = "timezones",
+        feature = "currencies",
+        feature = "languages",
+        feature = "call_codes",
+        feature = "sovereignty",
+        feature = "gini"
+    ))]
+    #[test]
+    fn query_builder() {
+        let india = Country::from_name("India").unwrap();
+        let query = || {
+            Country::query()
+                .region(Region::Asia)
+                .subregion("Southern Asia")
+                .capital("New Delhi")
+                .timezone("Asia/Kolkata")
+                .uses_currency("INR")
+                .speaks("hin")
+                .call_code("91")
+                .independent(true)
+        };
+        assert_eq!(query().collect(), [india]);
+        assert_eq!(
+            query().name("India").alpha_2("IN").alpha_3("IND").collect(),
+            [india]
+        );
+        assert!(query().independent(false).collect().is_empty());
+        assert!(
+            query()
+                .indicator(Indicator::Gini, 30.0..40.0)
+                .iter()
+                .eq([india])
+        );
+        assert!(
+            query()
+                .indicator(Indicator::Gini, ..30.0)
+                .collect()
+                .is_empty()
+        );
+        assert_eq!(Country::query().collect().len(), Country::iter().len());
+        let europe = Country::query().region(Region::Europe).into_iter();
+        assert!(europe.eq(Country::iter().filter(|country| country.region == Some("Europe"))));
+        for region in Country::iter().filter_map(|country| country.region) {
+            assert_eq!(Region::from_name(region).unwrap().as_str(), region);
+        }
+    }
+
+    #[cfg(any(
+        feature = "numeric_codes",
+        feature = "alt_spellings",
+        feature = "flags",
+        feature = "call_codes"
+    ))]
+    #[test]
+    fn query_field_filters() {
+        let india = Country::from_name("India").unwrap();
+        #[cfg(feature = "numeric_codes")]
+        {
+            assert_eq!(Country::query().numeric_code("356").collect(), [india]);
+            assert!(Country::query().numeric_code("999").collect().is_empty());
+        }
+        #[cfg(feature = "alt_spellings")]
+        {
+            assert_eq!(Country::query().alt_spelling("Bhārat").collect(), [india]);
+            assert!(Country::query().alt_spelling("India").collect().is_empty());
+        }
+        #[cfg(feature = "flags")]
+        {
+            assert_eq!(Country::query().flag_emoji("🇮🇳").collect(), [india]);
+            let svg = india.flag_svg.unwrap();
+            let png = india.flag_png.unwrap();
+            assert_eq!(
+                Country::query().flag_svg(svg).flag_png(png).collect(),
+                [india]
+            );
+            assert!(Country::query().flag_svg(png).collect().is_empty());
+        }
+        #[cfg(feature = "call_codes")]
+        {
+            assert_eq!(Country::query().call_code("+91").collect(), [india]);
+            let nanp = Country::query().call_code("1").into_iter();
+            let nanp: Vec<_> = nanp.map(|country| country.alpha_2).collect();
+            assert!(nanp.contains(&"US") && nanp.contains(&"CA"));
+        }
+    }
+
+    #[test]
+    fn lookup_detects_key_kind() {
+        let india = Country::from_name("India").unwrap();
+        let cases = [
+            ("IN", MatchKind::Alpha2),
+            ("in", MatchKind::Alpha2),
+            ("IND", MatchKind::Alpha3),
+            #[cfg(feature = "numeric_codes")]
+            ("356", MatchKind::Numeric),
+            (" India ", MatchKind::Name),
+            ("INDIA", MatchKind::Name),
+            #[cfg(feature = "alt_spellings")]
+            ("Bhārat", MatchKind::Alias),
+            ("new delhi", MatchKind::Capital),
+        ];
+        for (input, kind) in cases {
+            assert_eq!(
+                Country::lookup(input),
+                Some(Lookup {
+                    country: india,
+                    kind
+                })
+            );
+        }
+        #[cfg(feature = "numeric_codes")]
+        {
+            let afghanistan = Country::lookup("4").unwrap();
+            assert_eq!(afghanistan.country.alpha_3, "AFG");
+            assert_eq!(afghanistan.kind, MatchKind::Numeric);
+        }
+        #[cfg(feature = "ioc_codes")]
+        {
+            let netherlands = Country::lookup("NED").unwrap();
+            assert_eq!(netherlands.country.alpha_3, "NLD");
+            assert_eq!(netherlands.kind, MatchKind::Ioc);
+        }
+        // Two letters, but not an assigned alpha_2 code
+        #[cfg(feature = "alt_spellings")]
+        assert_eq!(Country::lookup("UK").unwrap().kind, MatchKind::Alias);
+        assert_eq!(Country::lookup("XX"), None);
+        assert_eq!(Country::lookup(""), None);
+        assert_eq!(Country::lookup("999"), None);
+    }
+
+    #[test]
+    fn parse_country() {
+        let india = Country::from_name("India").unwrap();
+        assert_eq!("IND".parse::<&Country>(), Ok(india));
+        assert_eq!(<&Country>::try_from("India"), Ok(india));
+        let error = "Atlantis".parse::<&Country>().unwrap_err();
+        assert_eq!(error.input(), "Atlantis");
+        assert_eq!(error.to_string(), r#"no country matches "Atlantis""#);
+    }
+
+    #[test]
+    fn lookup_errors() {
+        let error = Country::try_lookup("Inida").unwrap_err();
+        assert_eq!(
+            error,
+            LookupError::NotFound {
+                input: String::from("Inida"),
+                suggestions: vec![String::from("India")],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            r#"no country matches "Inida", did you mean India?"#
+        );
+        let error = Country::try_lookup("Kingston").unwrap_err();
+        let LookupError::Ambiguous {
+            candidates, kind, ..
+        } = &error
+        else {
+            panic!("{error:?}");
+        };
+        assert_eq!(*kind, MatchKind::Capital);
+        let names = candidates.iter().map(|country| country.name);
+        assert!(names.eq(["Jamaica", "Norfolk Island"]));
+        assert_eq!(
+            error.to_string(),
+            r#""Kingston" is the capital of Jamaica and Norfolk Island"#
+        );
+        let error = Country::try_lookup("XX").unwrap_err();
+        assert_eq!(
+            error,
+            LookupError::Unassigned {
+                input: String::from("XX"),
+                kind: MatchKind::Alpha2,
+            }
+        );
+        assert!(error.suggestions().is_empty());
+        assert_eq!(Country::try_lookup("Kabul").unwrap().country.alpha_2, "AF");
+        assert_eq!(
+            Country::try_from_name("Germny").unwrap_err().suggestions(),
+            ["Germany"]
+        );
+        assert_eq!(
+            Country::try_from_alpha_2("in"),
+            Err(LookupError::Malformed {
+                input: String::from("in"),
+                kind: MatchKind::Alpha2,
+            })
+        );
+        assert!(matches!(
+            Country::try_from_alpha_3("INDIA"),
+            Err(LookupError::Malformed { .. })
+        ));
+        assert!(matches!(
+            Country::try_from_ioc_code("1ND"),
+            Err(LookupError::Malformed { .. })
+        ));
+    }
+
+    #[cfg(all(
+        feature = "from_alpha_2",
+        feature = "from_alpha_3",
+        feature = "from_capitals"
+    ))]
+    #[test]
+    fn try_lookups_with_features() {
+        let india = Country::from_name("India").unwrap();
+        assert_eq!(Country::try_from_alpha_2("IN"), Ok(india));
+        assert_eq!(Country::try_from_alpha_3("IND"), Ok(india));
+        assert_eq!(Country::try_from_capital("New Delhi"), Ok(&[*india][..]));
+        assert!(matches!(
+            Country::try_from_alpha_2("XX"),
+            Err(LookupError::Unassigned { .. })
+        ));
+    }
+
+    #[cfg(not(feature = "from_alpha_2"))]
+    #[test]
+    fn try_lookups_without_features() {
+        assert_eq!(
+            Country::try_from_alpha_2("IN"),
+            Err(LookupError::FeatureDisabled {
+                input: String::from("IN"),
+                feature: "from_alpha_2",
+            })
+        );
+    }
+
+    #[cfg(feature = "gini")]
+    #[test]
+    fn indicator_summary() {
+        let names = ["Sweden", "Norway", "India", "Brazil", "Antarctica"];
+        let countries = names.map(|name| Country::from_name(name).unwrap());
+        let summary = Indicator::Gini.summary(countries).unwrap();
+        let mut gini = countries[..4]
+            .iter()
+            .map(|country| country.indicator(Indicator::Gini).unwrap())
+            .collect::<Vec<_>>();
+        gini.sort_by(f64::total_cmp);
+        assert_eq!(countries[4].gini, None);
+        assert_eq!(summary.count, 4);
+        assert_eq!(summary.min, gini[0]);
+        assert_eq!(summary.max, gini[3]);
+        assert_eq!(summary.median, (gini[1] + gini[2]) / 2.0);
+        assert_eq!(Indicator::Gini.summary([countries[4]]), None);
+        #[cfg(feature = "from_regions")]
+        {
+            assert_eq!(
+                Country::region_summary(Region::Polar, Indicator::Gini),
+                None
+            );
+            let europe = Country::region_summary(Region::Europe, Indicator::Gini).unwrap();
+            assert!(europe.count > 0 && europe.min < europe.max);
+        }
+        // Gini is an f32, widened without its binary error
+        assert_eq!(countries[2].indicator(Indicator::Gini), Some(35.7));
+        // Whole numbers are generated as float literals too
+        assert_eq!(
+            Country::from_name("Republic of Kosovo").unwrap().gini,
+            Some(29.0)
+        );
+    }
+
+    #[cfg(feature = "flags")]
+    #[test]
+    fn flag_emoji_round_trip() {
+        for country in NAMES.values() {
+            assert_eq!(
+                Country::from_flag_emoji(country.flag_emoji()),
+                Some(country)
+            );
+        }
+        assert_eq!(Country::from_flag_emoji("IN"), None);
+        assert_eq!(Country::from_flag_emoji(""), None);
+    }
+
+    #[cfg(feature = "sovereignty")]
+    #[test]
+    fn sovereign_and_territories() {
+        let united_states = Country::from_name("United States of America").unwrap();
+        let guam = Country::from_name("Guam").unwrap();
+        assert!(united_states.is_independent());
+        assert!(!guam.is_independent());
+        assert_eq!(guam.sovereign(), Some(united_states));
+        assert_eq!(united_states.sovereign(), None);
+        assert!(united_states.territories().contains(&guam));
+        assert!(guam.territories().is_empty());
+        // Marked independent upstream, corrected by build/sovereigns.json
+        assert!(!Country::from_name("Aruba").unwrap().is_independent());
+        assert!(Country::from_name("Uzbekistan").unwrap().is_independent());
+        assert!(!Country::from_name("Antarctica").unwrap().is_independent());
+    }
+
     #[cfg(feature = "from_subregions")]
     #[test]
     fn basic_country_fetching_from_subregion() {
         let southern_asia = Country::from_subregion("Southern Asia").unwrap();
         assert!(southern_asia.contains(Country::from_name("India").unwrap()));
     }
+
+    #[test]
+    fn registry_overrides() {
+        // Overrides take owned strings, eg. read from a tenant's configuration
+        let name = String::from("Taiwan, Province of China");
+        let registry = Registry::new()
+            .rename("TW", name)
+            .unwrap()
+            .alias("TW", "Chinese Taipei")
+            .unwrap()
+            .hide("RU")
+            .unwrap()
+            .assign("XS", String::from("XSC"), "Sark")
+            .unwrap();
+
+        let taiwan = registry.from_alpha_2("TW").unwrap();
+        assert_eq!(taiwan.name, "Taiwan, Province of China");
+        assert_eq!(taiwan.capital.as_deref(), Some("Taipei"));
+        assert_eq!(
+            registry.from_name("Taiwan, Province of China"),
+            Some(taiwan)
+        );
+        let lookup = registry.lookup("chinese taipei").unwrap();
+        assert_eq!((lookup.country, lookup.kind), (taiwan, MatchKind::Alias));
+        assert!(Country::lookup("chinese taipei").is_none());
+
+        assert!(registry.from_alpha_2("RU").is_none());
+        assert!(registry.lookup("Russia").is_none());
+        assert!(matches!(
+            registry.try_from_alpha_3("RUS"),
+            Err(LookupError::Unassigned { .. })
+        ));
+        assert_eq!(registry.iter().count(), COUNTRIES.len());
+
+        let sark = registry.try_lookup("xs").unwrap().country;
+        assert_eq!(sark.name, "Sark");
+        assert_eq!(sark.flag_emoji, "🇽🇸");
+        assert_eq!(registry.from_flag_emoji("🇽🇸"), Some(sark));
+        assert_eq!(
+            registry.try_lookup("Sarc").unwrap_err().suggestions(),
+            ["Sark"]
+        );
+        assert_eq!(
+            registry.try_from_name("Sarc").unwrap_err().to_string(),
+            r#"no country matches "Sarc", did you mean Sark?"#
+        );
+
+        // The aliases reach the countries found through the other lookups
+        let capital = registry.from_capital("Taipei").unwrap();
+        assert_eq!(capital, std::slice::from_ref(taiwan));
+        assert!(
+            capital[0]
+                .alt_spellings
+                .contains(&"Chinese Taipei".to_string())
+        );
+        let region = registry.from_region("Asia").unwrap();
+        assert!(region.contains(taiwan));
+
+        assert_eq!(
+            Registry::new().rename("TW", "India").unwrap_err(),
+            OverrideError::NameInUse("India".to_string())
+        );
+        #[cfg(feature = "alt_spellings")]
+        assert_eq!(
+            Registry::new().rename("TW", "BHĀRAT").unwrap_err(),
+            OverrideError::NameInUse("BHĀRAT".to_string())
+        );
+        assert_eq!(
+            Registry::new().alias("TW", "India").unwrap_err(),
+            OverrideError::NameInUse("India".to_string())
+        );
+        assert!(Registry::new().rename("IN", "india").is_ok());
+        assert_eq!(
+            Registry::new().rename("XX", "Nowhere").unwrap_err(),
+            OverrideError::UnknownCountry("XX".to_string())
+        );
+        assert_eq!(
+            Registry::new().assign("XK", "XKX", "Kosovo").unwrap_err(),
+            OverrideError::CodeInUse("XK".to_string())
+        );
+        assert_eq!(
+            Registry::new().assign("IN", "INX", "India").unwrap_err(),
+            OverrideError::NotUserAssigned("IN".to_string())
+        );
+        assert_eq!(
+            Registry::new().assign("XS", "SRK", "Sark").unwrap_err(),
+            OverrideError::NotUserAssigned("SRK".to_string())
+        );
+    }
+
+    #[test]
+    fn registry_lookups_match_country() {
+        let registry = Registry::new();
+        for country in Country::iter() {
+            let record = registry.from_alpha_2(country.alpha_2).unwrap();
+            assert_eq!(record, &CountryData::from(country));
+            assert_eq!(registry.from_alpha_3(country.alpha_3), Some(record));
+            assert_eq!(registry.from_name(country.name), Some(record));
+            assert_eq!(registry.from_flag_emoji(&record.flag_emoji), Some(record));
+            #[cfg(feature = "numeric_codes")]
+            if let Some(numeric_code) = country.numeric_code {
+                assert_eq!(registry.from_numeric_code(numeric_code), Some(record));
+            }
+            #[cfg(feature = "from_ioc_codes")]
+            if let Some(ioc_code) = country.ioc_code {
+                assert_eq!(registry.try_from_ioc_code(ioc_code), Ok(record));
+            }
+            #[cfg(feature = "sovereignty")]
+            assert_eq!(
+                registry
+                    .sovereign(record)
+                    .map(|sovereign| &sovereign.alpha_2),
+                country
+                    .sovereign()
+                    .map(|sovereign| sovereign.alpha_2.to_string())
+                    .as_ref()
+            );
+        }
+        #[cfg(feature = "from_regions")]
+        for region in Region::ALL {
+            let names = |countries: &[CountryData]| {
+                countries
+                    .iter()
+                    .map(|country| country.name.clone())
+                    .collect::<Vec<_>>()
+            };
+            let built_in = Country::from_region(region.as_str()).unwrap_or_default();
+            assert_eq!(
+                registry.from_region(region.as_str()).map(names),
+                Some(
+                    built_in
+                        .iter()
+                        .map(|country| country.name.to_string())
+                        .collect()
+                )
+                .filter(|names: &Vec<_>| !names.is_empty())
+            );
+        }
+        #[cfg(feature = "from_subregions")]
+        assert!(
+            registry
+                .from_subregion("Southern Asia")
+                .unwrap()
+                .iter()
+                .any(|country| country.alpha_2 == "IN")
+        );
+        #[cfg(feature = "from_capitals")]
+        assert_eq!(registry.try_from_capital("Kingston").unwrap().len(), 2);
+        assert!(matches!(
+            registry.try_lookup("Kingston"),
+            Err(LookupError::Ambiguous { .. })
+        ));
+        assert!(matches!(
+            registry.try_from_alpha_2("I1"),
+            Err(LookupError::Malformed { .. })
+        ));
+        #[cfg(feature = "sovereignty")]
+        {
+            let france = registry.from_alpha_2("FR").unwrap();
+            assert!(
+                registry
+                    .territories(france)
+                    .iter()
+                    .any(|territory| territory.alpha_2 == "GF")
+            );
+            assert!(
+                registry
+                    .independent_countries()
+                    .any(|country| country == france)
+            );
+        }
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn runtime_dataset_matches_built_in() {
+        let file =
+            std::fs::File::open(concat!(env!("CARGO_MANIFEST_DIR"), "/build/countries.json"))
+                .unwrap();
+        let (dataset, skipped) = Dataset::from_json_reader(std::io::BufReader::new(file)).unwrap();
+        assert!(skipped.is_empty(), "{skipped:?}");
+        assert_eq!(dataset.iter().count(), COUNTRIES.len());
+        for (record, country) in dataset.iter().zip(COUNTRIES) {
+            assert_eq!(record.name, country.name);
+            assert_eq!(record.alpha_3, country.alpha_3);
+            #[cfg(feature = "timezones")]
+            assert_eq!(record.timezones.len(), country.timezones.len());
+            #[cfg(feature = "sovereignty")]
+            assert_eq!(record.independent, country.is_independent());
+            #[cfg(feature = "flags")]
+            assert_eq!(record.flag_emoji, country.flag_emoji());
+            #[cfg(feature = "gini")]
+            assert_eq!(record.gini, country.gini);
+        }
+
+        let india = dataset.from_alpha_3("IND").unwrap();
+        assert_eq!(india.numeric_code.as_deref(), Some("356"));
+        assert_eq!(india.currencies[0].code.as_deref(), Some("INR"));
+        assert_eq!(dataset.from_flag_emoji("🇮🇳"), Some(india));
+        let lookup = dataset.lookup("bhārat").unwrap();
+        assert_eq!((lookup.country, lookup.kind), (india, MatchKind::Alias));
+        assert_eq!(dataset.from_capital("Kingston").map(<[_]>::len), Some(2));
+        assert_eq!(
+            dataset.from_region("Asia").map(<[_]>::len),
+            Country::from_region("Asia").map(<[_]>::len)
+        );
+        assert_eq!(
+            dataset.try_lookup("IND").map(|lookup| lookup.country),
+            Ok(india)
+        );
+        assert!(dataset.from_name("Atlantis").is_none());
+        assert!(matches!(
+            dataset.try_from_name("Inida"),
+            Err(LookupError::NotFound { suggestions, .. }) if suggestions == ["India"]
+        ));
+        #[cfg(feature = "sovereignty")]
+        {
+            let greenland = dataset.from_alpha_2("GL").unwrap();
+            let denmark = dataset.sovereign(greenland).unwrap();
+            assert_eq!(denmark.alpha_2, "DK");
+            assert!(dataset.territories(denmark).contains(&greenland));
+        }
+        assert!(Dataset::from_json_reader("{".as_bytes()).is_err());
+
+        let json = r#"[{"name": "Atlantis", "alpha2Code": "XA"}, {"alpha2Code": "XB"}, 7]"#;
+        let (dataset, skipped) = Dataset::from_json_reader(json.as_bytes()).unwrap();
+        assert_eq!(dataset.iter().count(), 1);
+        let skipped: Vec<_> = skipped.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            skipped,
+            [
+                r#"[1] "" (XB): no name, skipped"#,
+                r#"[2] "" (): not an object, skipped"#
+            ]
+        );
+    }
+
+    #[test]
+    fn data_info_describes_build() {
+        let info = super::data_info();
+        if option_env!("ISO_RS_TIMEZONES_PATH").is_none() {
+            assert_eq!(info.tzdata_version, Some("2026c"));
+        }
+        assert_eq!(info.countries, COUNTRIES.len());
+        assert!(info.countries_source.is_some());
+        match option_env!("ISO_RS_COUNTRIES_PATH") {
+            // From countries.meta.json, written by scripts/update-countries.sh
+            None => {
+                assert_eq!(info.country_records, 250);
+                assert!(info.countries_date.is_some());
+            }
+            Some(path) => {
+                assert!(path.ends_with(info.countries_source.unwrap()));
+                assert_eq!(info.countries_date, None);
+            }
+        }
+        #[cfg(feature = "timezones")]
+        assert!(info.timezones >= COUNTRIES.iter().map(|c| c.timezones.len()).sum());
+        assert!(
+            info.countries_hash
+                .bytes()
+                .all(|byte| byte.is_ascii_hexdigit())
+        );
+    }
+
+    #[cfg(feature = "subdivisions")]
+    #[test]
+    fn subdivisions() {
+        let united_states = Country::from_name("United States of America").unwrap();
+        let states = united_states.subdivisions();
+        assert!(states.iter().all(|state| state.code.starts_with("US-")));
+        assert_eq!(
+            states
+                .iter()
+                .filter(|state| state.category == "State")
+                .count(),
+            50
+        );
+        let california = Subdivision::from_code("US-CA").unwrap();
+        assert_eq!(california.name, "California");
+        assert_eq!(california.country(), Some(united_states));
+        assert_eq!(
+            united_states.subdivision_from_name("California"),
+            Some(california)
+        );
+        assert_eq!(Subdivision::from_code("US-XX"), None);
+        assert_eq!(Subdivision::from_code("us-ca"), None);
+        // Parents given without the country prefix in the data
+        let babek = Subdivision::from_code("AZ-BAB").unwrap();
+        assert_eq!(babek.parent, Some("AZ-NX"));
+        for subdivision in Subdivision::iter() {
+            assert!(subdivision.country().is_some(), "{subdivision:?}");
+            assert_eq!(subdivision.parent.is_some(), subdivision.parent().is_some());
+            assert!(
+                subdivision
+                    .country()
+                    .unwrap()
+                    .subdivisions()
+                    .contains(subdivision)
+            );
+        }
+        assert!(
+            Country::from_name("Antarctica")
+                .unwrap()
+                .subdivisions()
+                .is_empty()
+        );
+    }
+
+    #[cfg(feature = "historic")]
+    #[test]
+    fn historic_countries() {
+        let codes = |countries: Vec<&Country>| -> Vec<&str> {
+            countries.iter().map(|country| country.alpha_2).collect()
+        };
+        assert_eq!(codes(Country::resolve_historic("AN")), ["BQ", "CW", "SX"]);
+        assert_eq!(codes(Country::resolve_historic("ANT")), ["BQ", "CW", "SX"]);
+        assert_eq!(codes(Country::resolve_historic("YU")), ["ME", "RS"]);
+        assert_eq!(
+            codes(Country::resolve_historic("CS")),
+            ["CZ", "ME", "RS", "SK"]
+        );
+        assert_eq!(Country::resolve_historic("SU").len(), 13);
+        assert_eq!(codes(Country::resolve_historic("US")), ["US"]);
+        // Codes reassigned since resolve to the current country, and only the
+        // alpha_4 code reaches the former one
+        assert_eq!(codes(Country::resolve_historic("SK")), ["SK"]);
+        assert_eq!(codes(Country::resolve_historic("SKIN")), ["IN"]);
+        assert_eq!(codes(Country::resolve_historic("GE")), ["GE"]);
+        assert_eq!(codes(Country::resolve_historic("GEO")), ["GE"]);
+        assert_eq!(codes(Country::resolve_historic("GEHH")), ["KI", "TV"]);
+        for code in ["AI", "BQ", "BY"] {
+            assert_eq!(codes(Country::resolve_historic(code)), [code]);
+        }
+        assert!(Country::resolve_historic("XX").is_empty());
+        for historic in HistoricCountry::iter() {
+            assert_eq!(
+                historic.successors().len(),
+                historic.successors.len(),
+                "{historic:?}"
+            );
+            assert!(historic.alpha_4.starts_with(historic.alpha_2));
+        }
+        let east_germany = HistoricCountry::from_alpha_4("DDDE").unwrap();
+        assert!(east_germany.in_use_on("1990-10-02"));
+        assert!(!east_germany.in_use_on("1990-10-30"));
+        let upper_volta = HistoricCountry::from_alpha_3("HVO")[0];
+        assert!(upper_volta.in_use_on("1984-12-31"));
+        assert!(!upper_volta.in_use_on("1985-01-01"));
+        let serbia_and_montenegro = HistoricCountry::from_alpha_4("CSXX").unwrap();
+        assert_eq!(serbia_and_montenegro.introduced, "2003-07-23");
+        assert!(!serbia_and_montenegro.in_use_on("2003-07-22"));
+        assert!(serbia_and_montenegro.in_use_on("2003-07-23"));
+        let metropolitan_france = HistoricCountry::from_alpha_4("FXFR").unwrap();
+        assert!(!metropolitan_france.in_use_on("1992-12-31"));
+        assert!(metropolitan_france.in_use_on("1993-01-01"));
+        assert!(
+            HistoricCountry::in_use("1980-01-01")
+                .iter()
+                .all(|country| country.alpha_4 != "CSXX" && country.alpha_4 != "FXFR")
+        );
+        assert_eq!(HistoricCountry::from_alpha_4("XXXX"), None);
+    }
+
+    #[test]
+    fn code_status() {
+        assert_eq!(classify_alpha_2("US"), CodeStatus::OfficiallyAssigned);
+        assert_eq!(classify_alpha_2("EU"), CodeStatus::ExceptionallyReserved);
+        assert_eq!(classify_alpha_2("AN"), CodeStatus::TransitionallyReserved);
+        assert_eq!(classify_alpha_2("AA"), CodeStatus::UserAssigned);
+        assert_eq!(classify_alpha_2("QM"), CodeStatus::UserAssigned);
+        assert_eq!(classify_alpha_2("QL"), CodeStatus::Unassigned);
+        assert_eq!(classify_alpha_2("us"), CodeStatus::Unassigned);
+        assert_eq!(classify_alpha_2("USA"), CodeStatus::Unassigned);
+        let codes: Vec<_> = ReservedCode::iter().map(|code| code.alpha_2).collect();
+        assert!(codes.is_sorted());
+        for reserved in ReservedCode::iter() {
+            assert_eq!(classify_alpha_2(reserved.alpha_2), reserved.status);
+            #[cfg(feature = "historic")]
+            if reserved.status == CodeStatus::TransitionallyReserved {
+                assert!(!reserved.historic().is_empty(), "{reserved:?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "currencies")]
+    #[test]
+    fn iso_4217_currencies() {
+        let codes: Vec<_> = Currency::iter().map(|currency| currency.code).collect();
+        assert!(codes.is_sorted());
+        assert_eq!(Currency::from_code("BHD").unwrap().minor_unit, Some(3));
+        assert_eq!(Currency::from_code("CLF").unwrap().minor_unit, Some(4));
+        let sdr = Currency::from_code("XDR").unwrap();
+        assert_eq!(sdr.kind, Some(CurrencyKind::UnitOfAccount));
+        assert!(sdr.countries().is_empty());
+        assert_eq!(
+            Currency::from_code("USN").unwrap().kind,
+            Some(CurrencyKind::Fund)
+        );
+        assert_eq!(Currency::from_code("EUR").unwrap().symbol, Some("€"));
+        assert_eq!(Currency::from_code("eur"), None);
+        // Every country currency with an ISO 4217 code is joined to its entry
+        for country in Country::iter() {
+            for currency in country.currencies {
+                let Some(iso) = currency.code.and_then(Currency::from_code) else {
+                    assert_eq!(currency.kind, None, "{currency:?}");
+                    continue;
+                };
+                assert_eq!(currency.numeric_code, iso.numeric_code);
+                assert_eq!(currency.minor_unit, iso.minor_unit);
+                assert_eq!(currency.kind, iso.kind);
+                assert!(iso.countries().contains(&country));
+            }
+        }
+        let faroe_islands = Country::from_name("Faroe Islands").unwrap();
+        assert!(
+            faroe_islands
+                .currencies
+                .iter()
+                .any(|currency| currency.kind.is_none())
+        );
+    }
+
+    #[cfg(feature = "currencies")]
+    #[test]
+    fn historic_currencies() {
+        let franc = Currency::from_code("FRF").unwrap();
+        assert!(!franc.is_active());
+        assert_eq!(franc.withdrawn, Some("2002-03"));
+        assert_eq!(franc.replacement(), Currency::from_code("EUR"));
+        assert!(franc.countries().is_empty());
+        assert!(Currency::from_code("EUR").unwrap().is_active());
+        assert_eq!(Currency::from_code("EUR").unwrap().replacement(), None);
+        // In the ISO 4217 data, but withdrawn since
+        let kuna = Currency::from_code("HRK").unwrap();
+        #[cfg(feature = "iso4217")]
+        assert_eq!(kuna.numeric_code, Some("191"));
+        assert!(!kuna.is_active());
+        for currency in Currency::iter() {
+            if let Some(replaced_by) = currency.replaced_by {
+                assert!(!currency.is_active(), "{currency:?}");
+                assert!(Currency::from_code(replaced_by).is_some(), "{currency:?}");
+                assert!(currency.replacement().unwrap().is_active());
+            }
+            if let Some(rate) = currency.conversion_rate {
+                assert!(rate.parse::<f64>().is_ok_and(|rate| rate > 0.0));
+            }
+        }
+    }
+
+    #[cfg(feature = "currencies")]
+    #[test]
+    fn money() {
+        let currency = |code| Currency::from_code(code).unwrap();
+        let euros = |amount| Money::from_decimal(amount, currency("EUR")).unwrap();
+        let yens = |amount| Money::from_decimal(amount, currency("JPY"));
+        assert_eq!(euros("12.50").amount, 1250);
+        assert_eq!(euros("12.5").amount, 1250);
+        assert_eq!(euros("1,234").amount, 123400);
+        assert_eq!(euros("1,234,567").amount, 123456700);
+        assert_eq!(euros("1 234.50").amount, 123450);
+        assert_eq!(euros("1'234.5").amount, 123450);
+        assert_eq!(euros("-0.01").amount, -1);
+        assert_eq!(euros(".5").amount, 50);
+        assert_eq!(euros("1,23,456.78").amount, 12345678);
+        assert_eq!(yens("1,000").unwrap().amount, 1000);
+        // "." is always the decimal separator, and extra decimals are refused
+        for (amount, minor_unit) in [("1.000", 0), ("1.5", 0)] {
+            assert_eq!(
+                yens(amount),
+                Err(MoneyError::TooManyDecimals(amount.to_string(), minor_unit))
+            );
+        }
+        for amount in ["1.234", "12.345", "0.005", "1,234,567.891"] {
+            assert_eq!(
+                Money::from_decimal(amount, currency("EUR")),
+                Err(MoneyError::TooManyDecimals(amount.to_string(), 2))
+            );
+        }
+        for invalid in [
+            "",
+            "-",
+            "abc",
+            "1.2,3.4",
+            "1e5",
+            "12.50.3",
+            "1,2345.6",
+            "12,5",
+            "1.234.567",
+            "1 234,50",
+        ] {
+            assert!(
+                Money::from_decimal(invalid, currency("EUR")).is_err(),
+                "{invalid:?}"
+            );
+        }
+        assert_eq!(
+            Money::from_decimal("99999999999999999999", currency("EUR")),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(
+            Money::new(1, currency("XAU")),
+            Err(MoneyError::NoMinorUnit("XAU".to_string()))
+        );
+
+        assert_eq!(
+            Money::parse("US$12.50").unwrap(),
+            Money::new(1250, currency("USD")).unwrap()
+        );
+        assert_eq!("12.50 €".parse::<Money>().unwrap(), euros("12.50"));
+        assert_eq!(Money::parse("-€3").unwrap(), euros("-3"));
+        assert_eq!(Money::parse("€-3").unwrap(), euros("-3"));
+        assert_eq!(Money::parse("JPY 1,000").unwrap().amount, 1000);
+        assert!(matches!(
+            Money::parse("$5"),
+            Err(MoneyError::AmbiguousSymbol { .. })
+        ));
+        assert_eq!(
+            Money::parse("5 ZZZ"),
+            Err(MoneyError::UnknownCurrency("ZZZ".to_string()))
+        );
+        assert!(Money::parse("5").is_err());
+        assert!(Money::parse("€5 EUR").is_err());
+
+        let yen = Money::new(500, currency("JPY")).unwrap();
+        assert_eq!(yen.to_string(), "500 JPY");
+        assert_eq!(
+            euros("1").checked_add(yen),
+            Err(MoneyError::CurrencyMismatch(
+                "EUR".to_string(),
+                "JPY".to_string()
+            ))
+        );
+        assert_eq!(euros("1").checked_sub(euros("2.5")).unwrap(), euros("-1.5"));
+        assert_eq!(euros("-1.5").to_string(), "-1.50 EUR");
+        assert_eq!(euros("0.05").to_string(), "0.05 EUR");
+        assert_eq!(
+            Money::new(i64::MAX, currency("EUR"))
+                .unwrap()
+                .checked_add(euros("0.01")),
+            Err(MoneyError::Overflow)
+        );
+        let francs = |amount| Money::from_decimal(amount, currency("CHF")).unwrap();
+        assert_eq!(francs("12.32").round_cash().unwrap(), francs("12.30"));
+        assert_eq!(francs("12.33").round_cash().unwrap(), francs("12.35"));
+        assert_eq!(francs("-12.33").round_cash().unwrap(), francs("-12.35"));
+        assert_eq!(euros("12.33").round_cash().unwrap(), euros("12.33"));
+
+        // Equality goes by code, like the arithmetic
+        let copy = Currency {
+            name: Some("Euro (copy)"),
+            ..*currency("EUR")
+        };
+        let copy = Money::new(1250, Box::leak(Box::new(copy))).unwrap();
+        assert_eq!(copy, euros("12.50"));
+        assert_eq!(copy.checked_add(euros("1")).unwrap(), euros("13.50"));
+        assert_ne!(copy, euros("12.51"));
+    }
+
+    #[cfg(feature = "currencies")]
+    #[test]
+    fn rate_tables() {
+        let currency = |code| Currency::from_code(code).unwrap();
+        let money = |input| Money::parse(input).unwrap();
+        let empty = MemoryRateTable::new();
+        // Fixed rates need no table, either way round and between two withdrawn currencies
+        assert_eq!(
+            empty.convert(money("1.96 DEM"), currency("EUR")),
+            Ok(money("1 EUR"))
+        );
+        assert_eq!(
+            empty.convert(money("100 EUR"), currency("FRF")),
+            Ok(money("655.96 FRF"))
+        );
+        assert_eq!(
+            empty.convert(money("1000 DEM"), currency("FRF")),
+            Ok(money("3353.85 FRF"))
+        );
+        assert_eq!(
+            empty.convert(money("100000000 VEB"), currency("VES")),
+            Ok(money("1.00 VES"))
+        );
+        assert_eq!(
+            empty.convert(money("1 EUR"), currency("USD")),
+            Err(MoneyError::NoRate("EUR".to_string(), "USD".to_string()))
+        );
+
+        let mut rates = MemoryRateTable::new()
+            .with_rate(currency("USD"), currency("EUR"), 0.5)
+            .unwrap()
+            .with_rate(currency("GBP"), currency("EUR"), 1.25)
+            .unwrap();
+        // Rates that can't be used are refused rather than kept
+        for rate in [-1.0, 0.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                rates.insert(currency("EUR"), currency("CHF"), rate),
+                Err(MoneyError::InvalidRate {
+                    from: "EUR".to_string(),
+                    to: "CHF".to_string(),
+                    rate: rate.to_string(),
+                })
+            );
+        }
+        // Rates are kept by code, so currencies without one can't share a key
+        let codeless = Box::leak(Box::new(Currency {
+            name: Some("Faroese króna"),
+            ..Currency::default()
+        }));
+        assert_eq!(
+            rates.insert(codeless, currency("EUR"), 0.13),
+            Err(MoneyError::NoCode("Faroese króna".to_string()))
+        );
+        assert_eq!(rates.rate(codeless, currency("EUR")), None);
+        assert_eq!(
+            rates.conversion_rate(currency("EUR"), currency("USD")),
+            Some(2.0)
+        );
+        // Triangulating needs a base
+        assert_eq!(
+            rates.conversion_rate(currency("USD"), currency("GBP")),
+            None
+        );
+        let rates = rates.with_base(currency("EUR"));
+        assert_eq!(
+            rates.convert(money("US$10"), currency("GBP")),
+            Ok(money("4.00 GBP"))
+        );
+        assert_eq!(
+            rates.conversion_rate(currency("EUR"), currency("CHF")),
+            None
+        );
+        assert_eq!(
+            rates.convert(money("10 EUR"), currency("XAU")),
+            Err(MoneyError::NoMinorUnit("XAU".to_string()))
+        );
+        assert_eq!(
+            rates.convert(money("1.01 USD"), currency("EUR")),
+            Ok(money("0.51 EUR"))
+        );
+
+        // Past 2^53 minor units, where an f64 can't hold every amount
+        let usd = currency("USD");
+        let eur = currency("EUR");
+        let rates = MemoryRateTable::new().with_rate(usd, eur, 1.0).unwrap();
+        let large = (1 << 53) + 1;
+        assert_eq!(
+            rates.convert(Money::new(large, usd).unwrap(), eur),
+            Money::new(large, eur)
+        );
+        let rates = MemoryRateTable::new().with_rate(usd, eur, 0.5).unwrap();
+        assert_eq!(
+            rates.convert(Money::new(i64::MAX, usd).unwrap(), eur),
+            Money::new(i64::MAX / 2 + 1, eur)
+        );
+        assert_eq!(
+            rates.convert(Money::new(-large, usd).unwrap(), eur),
+            Money::new(-(large / 2 + 1), eur)
+        );
+        let rates = MemoryRateTable::new().with_rate(usd, eur, 2.0).unwrap();
+        assert_eq!(
+            rates.convert(Money::new(i64::MAX / 2 + 1, usd).unwrap(), eur),
+            Err(MoneyError::Overflow)
+        );
+        let yen = currency("JPY");
+        let rates = MemoryRateTable::new().with_rate(usd, yen, 0.25).unwrap();
+        assert_eq!(
+            rates.convert(Money::new(large, usd).unwrap(), yen),
+            Money::new(large / 400, yen)
+        );
+    }
+
+    #[cfg(feature = "currencies")]
+    #[test]
+    fn currency_symbols() {
+        let codes = |currencies: Vec<&Currency>| -> Vec<&str> {
+            currencies
+                .iter()
+                .filter_map(|currency| currency.code)
+                .collect()
+        };
+        let pounds = codes(Currency::from_symbol("£"));
+        assert!(pounds.contains(&"GBP") && pounds.contains(&"FKP"));
+        assert!(pounds.is_sorted());
+        assert!(Currency::from_symbol("kr").len() > 3);
+        assert_eq!(codes(Currency::from_symbol("CA$")), ["CAD"]);
+        assert!(Currency::from_symbol("DM").is_empty());
+        assert!(Currency::from_symbol("").is_empty());
+        // Only currencies with a narrow symbol of their own get one
+        for currency in Currency::iter() {
+            if let Some(narrow) = currency.narrow_symbol() {
+                assert_eq!(Currency::from_symbol(narrow), [currency]);
+            }
+        }
+        assert_eq!(
+            Currency::iter()
+                .filter(|currency| currency.narrow_symbol.is_some())
+                .count(),
+            10
+        );
+
+        let country = |name| Country::from_name(name).unwrap();
+        // Countries' currencies carry the same usage data
+        assert_eq!(country("Switzerland").currencies[0].cash_increment(), 5);
+        assert_eq!(
+            country("New Zealand").currencies[0].narrow_symbol(),
+            Some("NZ$")
+        );
+        let resolve = |symbol, name| Currency::resolve_symbol(symbol, country(name));
+        assert_eq!(
+            resolve("£", "Falkland Islands (Malvinas)").unwrap().code,
+            Some("FKP")
+        );
+        assert_eq!(resolve("$", "Australia").unwrap().code, Some("AUD"));
+        assert_eq!(resolve("US$", "Australia").unwrap().code, Some("USD"));
+        assert_eq!(resolve("kr", "India"), None);
+        // The first of the country's currencies with the symbol
+        assert_eq!(resolve("kr", "Faroe Islands").unwrap().code, Some("DKK"));
+        assert!(
+            Currency::from_symbol("kr")
+                .iter()
+                .any(|currency| currency.code == Some("FOK") && currency.kind.is_none())
+        );
+
+        assert_eq!(Money::parse("CA$5").unwrap().currency.code, Some("CAD"));
+        assert_eq!(Money::parse("HK$5").unwrap().currency.code, Some("HKD"));
+    }
+
+    #[cfg(feature = "iso639")]
+    #[test]
+    fn iso_639_languages() {
+        let codes: Vec<_> = Language::iter().map(|language| language.iso639_3).collect();
+        assert!(codes.is_sorted());
+        let german = Language::from_code("ger").unwrap();
+        assert_eq!(german.iso639_1, Some("de"));
+        assert_eq!(german.iso639_2, Some("deu"));
+        assert_eq!(german.iso639_2b, Some("ger"));
+        assert_eq!(Language::from_code("de"), Some(german));
+        assert_eq!(Language::from_code("deu"), Some(german));
+        assert_eq!(Language::from_name("German"), Some(german));
+        // In ISO 639-3 but not 639-2
+        assert!(Language::from_iso639_3("cmn").is_some());
+        assert_eq!(Language::from_iso639_2("cmn"), None);
+        let chinese = Language::from_iso639_1("zh").unwrap();
+        assert_eq!(chinese.scope, Some(LanguageScope::Macrolanguage));
+        assert!(
+            chinese
+                .members()
+                .iter()
+                .any(|member| member.iso639_3 == Some("yue"))
+        );
+        assert_eq!(
+            Language::from_name("Latin").unwrap().language_type,
+            Some(LanguageType::Ancient)
+        );
+        assert_eq!(
+            Language::from_code("und").unwrap().scope,
+            Some(LanguageScope::Special)
+        );
+        for language in Language::iter() {
+            if let Some(macrolanguage) = language.macrolanguage() {
+                assert!(macrolanguage.members().contains(&language));
+            }
+            if language.scope == Some(LanguageScope::Macrolanguage) {
+                assert!(!language.members().is_empty(), "{language:?}");
+            }
+        }
+        let akan = Language::from_iso639_1("ak").unwrap();
+        assert_eq!(
+            Language::from_iso639_3("twi").unwrap().macrolanguage(),
+            Some(akan)
+        );
+        assert_eq!(
+            Language::from_iso639_3("zsm")
+                .unwrap()
+                .macrolanguage()
+                .unwrap()
+                .iso639_1,
+            Some("ms")
+        );
+        // ISO 639-2 collective codes are not in the ISO 639-3 registry
+        assert_eq!(Language::from_iso639_2("sla"), None);
+        // Country languages are joined to their ISO 639-3 entry
+        #[cfg(feature = "languages")]
+        for country in Country::iter() {
+            for language in country.languages {
+                let Some(iso) = language.iso639_3.and_then(Language::from_iso639_3) else {
+                    continue;
+                };
+                assert_eq!(language.iso639_2b, iso.iso639_2b);
+                assert_eq!(language.scope, iso.scope);
+            }
+        }
+        #[cfg(feature = "languages")]
+        assert_eq!(
+            Country::from_name("France").unwrap().languages[0].iso639_2b,
+            Some("fre")
+        );
+    }
+
+    #[cfg(feature = "iso15924")]
+    #[test]
+    fn iso_15924_scripts() {
+        let codes: Vec<_> = Script::iter().map(|script| script.alpha_4).collect();
+        assert!(codes.is_sorted());
+        let devanagari = Script::from_alpha_4("deva").unwrap();
+        assert_eq!(devanagari.numeric_code, "315");
+        assert_eq!(Script::from_numeric_code("315"), Some(devanagari));
+        assert_eq!(
+            Script::from_property_value_alias("devanagari"),
+            Some(devanagari)
+        );
+        assert_eq!(
+            Script::from_alpha_4("Hans").unwrap().property_value_alias,
+            None
+        );
+        assert_eq!(Script::from_alpha_4("Xxxx"), None);
+        assert_eq!(Script::from_alpha_4(""), None);
+        // Aliases are unique, so they map back to their script
+        for script in Script::iter() {
+            if let Some(alias) = script.property_value_alias {
+                assert_eq!(Script::from_property_value_alias(alias), Some(script));
+            }
+        }
+        // Every language with a script points at a known one
+        #[cfg(feature = "iso639")]
+        for language in Language::iter() {
+            assert_eq!(
+                language.script.is_some(),
+                language.default_script().is_some()
+            );
+        }
+        #[cfg(feature = "languages")]
+        {
+            let serbia = Country::from_name("Serbia").unwrap();
+            assert_eq!(serbia.scripts()[0].alpha_4, "Cyrl");
+            let japan = Country::from_name("Japan").unwrap();
+            assert_eq!(japan.scripts()[0].alpha_4, "Jpan");
+        }
+    }
 }
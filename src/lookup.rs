@@ -0,0 +1,443 @@
+use crate::{COUNTRIES, Country, LookupError, NAMES};
+use std::fmt;
+use std::str::FromStr;
+
+/// The kind of key a [`Country::lookup`](struct.Country.html#method.lookup) input matched.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// ISO 3166-1 alpha_2 code, eg. "IN"
+    Alpha2,
+    /// ISO 3166-1 alpha_3 code, eg. "IND"
+    Alpha3,
+    /// ISO 3166-1 numeric code, eg. "356"
+    Numeric,
+    /// International Olympic Committee code, eg. "GER"
+    Ioc,
+    /// Name of the country, eg. "India"
+    Name,
+    /// Alternative spelling of the country's name, eg. "Bhārat"
+    Alias,
+    /// Name of the country's capital, eg. "New Delhi"
+    Capital,
+}
+
+impl fmt::Display for MatchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MatchKind::Alpha2 => "alpha_2 code",
+            MatchKind::Alpha3 => "alpha_3 code",
+            MatchKind::Numeric => "numeric code",
+            MatchKind::Ioc => "IOC code",
+            MatchKind::Name => "name",
+            MatchKind::Alias => "alternative spelling",
+            MatchKind::Capital => "capital",
+        })
+    }
+}
+
+/// A country found by [`Country::lookup`](struct.Country.html#method.lookup),
+/// along with the kind of key it was found by.
+///
+/// `C` is how the country is referred to, which is only something other than
+/// `&'static Country` for the records of a runtime `Dataset`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Lookup<C = &'static Country> {
+    /// The matched country
+    pub country: C,
+    /// The kind of key the input matched
+    pub kind: MatchKind,
+}
+
+// The keys `lookup` matches on, so the static countries and owned records can
+// share it
+pub(crate) trait Keys {
+    fn name(&self) -> &str;
+    fn capital(&self) -> Option<&str>;
+    fn alpha_2(&self) -> &str;
+    fn alpha_3(&self) -> &str;
+    fn numeric_code(&self) -> Option<&str>;
+    fn ioc_code(&self) -> Option<&str>;
+    // Whether the lowercased input is one of the alternative spellings
+    fn is_alt_spelling(&self, input: &str) -> bool;
+}
+
+impl Keys for Country {
+    fn name(&self) -> &str {
+        self.name
+    }
+    fn capital(&self) -> Option<&str> {
+        self.capital
+    }
+    fn alpha_2(&self) -> &str {
+        self.alpha_2
+    }
+    fn alpha_3(&self) -> &str {
+        self.alpha_3
+    }
+    #[cfg(feature = "numeric_codes")]
+    fn numeric_code(&self) -> Option<&str> {
+        self.numeric_code
+    }
+    #[cfg(not(feature = "numeric_codes"))]
+    fn numeric_code(&self) -> Option<&str> {
+        None
+    }
+    #[cfg(feature = "ioc_codes")]
+    fn ioc_code(&self) -> Option<&str> {
+        self.ioc_code
+    }
+    #[cfg(not(feature = "ioc_codes"))]
+    fn ioc_code(&self) -> Option<&str> {
+        None
+    }
+    #[cfg(feature = "alt_spellings")]
+    fn is_alt_spelling(&self, input: &str) -> bool {
+        self.alt_spellings
+            .iter()
+            .any(|spelling| spelling.to_lowercase() == input)
+    }
+    #[cfg(not(feature = "alt_spellings"))]
+    fn is_alt_spelling(&self, _input: &str) -> bool {
+        false
+    }
+}
+
+// The kind of code a non-empty input is shaped like, if any
+fn code_kind(input: &str) -> Option<MatchKind> {
+    if input.len() <= 3 && input.bytes().all(|byte| byte.is_ascii_digit()) {
+        Some(MatchKind::Numeric)
+    } else if !input.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        None
+    } else if input.len() == 2 {
+        Some(MatchKind::Alpha2)
+    } else if input.len() == 3 {
+        Some(MatchKind::Alpha3)
+    } else {
+        None
+    }
+}
+
+// Every country matching a non-empty input at the first kind of key that matches
+// at all, following the order documented on `Country::lookup`. `is_alias` gets
+// each country and the lowercased input.
+pub(crate) fn candidates<'a, T, I>(
+    countries: I,
+    is_alias: &dyn Fn(&T, &str) -> bool,
+    input: &str,
+) -> Option<(MatchKind, Vec<&'a T>)>
+where
+    T: Keys,
+    I: Iterator<Item = &'a T> + Clone,
+{
+    let find = |kind: MatchKind, matches: &dyn Fn(&T) -> bool| {
+        let countries = countries
+            .clone()
+            .filter(|country| matches(country))
+            .collect::<Vec<_>>();
+        (!countries.is_empty()).then_some((kind, countries))
+    };
+    let code = |code: &str| code.eq_ignore_ascii_case(input);
+
+    let found = match code_kind(input) {
+        Some(MatchKind::Numeric) => {
+            // Numeric codes are zero padded, "4" is Afghanistan's "004"
+            let numeric = format!("{input:0>3}");
+            find(MatchKind::Numeric, &|country| {
+                country.numeric_code() == Some(numeric.as_str())
+            })
+        }
+        Some(MatchKind::Alpha2) => find(MatchKind::Alpha2, &|country| code(country.alpha_2())),
+        Some(_) => find(MatchKind::Alpha3, &|country| code(country.alpha_3())).or_else(|| {
+            find(MatchKind::Ioc, &|country| {
+                country.ioc_code().is_some_and(code)
+            })
+        }),
+        None => None,
+    };
+    found.or_else(|| {
+        let input = input.to_lowercase();
+        let text = |text: &str| text.to_lowercase() == input;
+        find(MatchKind::Name, &|country| text(country.name()))
+            .or_else(|| find(MatchKind::Alias, &|country| is_alias(country, &input)))
+            .or_else(|| {
+                find(MatchKind::Capital, &|country| {
+                    country.capital().is_some_and(text)
+                })
+            })
+    })
+}
+
+// The first match for an input, as returned by `Country::lookup`
+pub(crate) fn first<'a, T, I>(
+    countries: I,
+    is_alias: &dyn Fn(&T, &str) -> bool,
+    input: &str,
+) -> Option<Lookup<&'a T>>
+where
+    T: Keys,
+    I: Iterator<Item = &'a T> + Clone,
+{
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    candidates(countries, is_alias, input).map(|(kind, countries)| Lookup {
+        country: countries[0],
+        kind,
+    })
+}
+
+// The only match for an input, or why there isn't one, as returned by
+// `Country::try_lookup`. Suggestions are drawn from `names`.
+pub(crate) fn only<'a, T, I, N>(
+    countries: I,
+    is_alias: &dyn Fn(&T, &str) -> bool,
+    names: N,
+    input: &str,
+) -> Result<Lookup<&'a T>, LookupError<&'a T>>
+where
+    T: Keys,
+    I: Iterator<Item = &'a T> + Clone,
+    N: Iterator<Item = &'a str>,
+{
+    let trimmed = input.trim();
+    let found = if trimmed.is_empty() {
+        None
+    } else {
+        candidates(countries, is_alias, trimmed)
+    };
+    match found {
+        Some((kind, countries)) if countries.len() == 1 => Ok(Lookup {
+            country: countries[0],
+            kind,
+        }),
+        Some((kind, candidates)) => Err(LookupError::Ambiguous {
+            input: input.to_string(),
+            kind,
+            candidates,
+        }),
+        None => match code_kind(trimmed) {
+            Some(kind) if !trimmed.is_empty() => Err(LookupError::Unassigned {
+                input: input.to_string(),
+                kind,
+            }),
+            _ => Err(LookupError::NotFound {
+                input: input.to_string(),
+                suggestions: suggestions(trimmed, names),
+            }),
+        },
+    }
+}
+
+// Levenshtein distance over chars, ignoring case
+fn distance(a: &str, b: &str) -> usize {
+    let a = a.to_lowercase().chars().collect::<Vec<_>>();
+    let b = b.to_lowercase().chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+// Up to three of the names within a few typos of the input, closest first
+pub(crate) fn suggestions<'a>(input: &str, names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let limit = (input.chars().count() / 3).max(2);
+    let mut names = names
+        .map(|name| (distance(input, name), name))
+        .filter(|(distance, _)| *distance <= limit)
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+        .into_iter()
+        .take(3)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+// Check that a code is the expected number of uppercase ASCII letters
+pub(crate) fn check_code<C>(
+    input: &str,
+    len: usize,
+    kind: MatchKind,
+) -> Result<(), LookupError<C>> {
+    if input.len() == len && input.bytes().all(|byte| byte.is_ascii_uppercase()) {
+        Ok(())
+    } else {
+        Err(LookupError::Malformed {
+            input: input.to_string(),
+            kind,
+        })
+    }
+}
+
+impl Country {
+    /// Find a country from any identifier, without knowing up front whether it is
+    /// a code, a name or a capital.
+    ///
+    /// Codes are tried first, picked by the shape of the input: digits for numeric
+    /// codes, two letters for alpha_2 and three letters for alpha_3, then IOC codes.
+    /// Anything else, or a code that matches nothing, is tried as a name, an
+    /// alternative spelling and a capital, in that order. Matching ignores case and
+    /// surrounding whitespace. If the input matches several countries, like a
+    /// capital shared by two of them, the first one by alpha_2 code is returned.
+    /// Numeric codes, IOC codes and alternative spellings are only matched with
+    /// the `numeric_codes`, `ioc_codes` and `alt_spellings` features.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    /// use iso_rs::MatchKind;
+    ///
+    /// # #[cfg(feature = "ioc_codes")] {
+    /// let lookup = Country::lookup("ger").unwrap();
+    /// assert_eq!(lookup.country.name, "Germany");
+    /// assert_eq!(lookup.kind, MatchKind::Ioc);
+    /// # }
+    ///
+    /// # #[cfg(feature = "alt_spellings")] {
+    /// let lookup = Country::lookup("UK").unwrap();
+    /// assert_eq!(lookup.country.alpha_2, "GB");
+    /// assert_eq!(lookup.kind, MatchKind::Alias);
+    /// # }
+    /// ```
+    pub fn lookup(input: &str) -> Option<Lookup> {
+        first(COUNTRIES.iter(), &Country::is_alt_spelling, input)
+    }
+    /// Find a country from any identifier like [`lookup`](#method.lookup), but
+    /// saying why nothing was found, and refusing to pick between several matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    /// use iso_rs::LookupError;
+    ///
+    /// let error = Country::try_lookup("Inida").unwrap_err();
+    /// assert_eq!(error.suggestions(), ["India"]);
+    ///
+    /// let error = Country::try_lookup("Kingston").unwrap_err();
+    /// assert!(matches!(error, LookupError::Ambiguous { .. }));
+    /// ```
+    pub fn try_lookup(input: &str) -> Result<Lookup, LookupError> {
+        only(
+            COUNTRIES.iter(),
+            &Country::is_alt_spelling,
+            NAMES.keys().copied(),
+            input,
+        )
+    }
+    /// Get the country from its name like [`from_name`](#method.from_name),
+    /// suggesting close names if there is none
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let error = Country::try_from_name("Germny").unwrap_err();
+    /// assert_eq!(error.suggestions(), ["Germany"]);
+    /// ```
+    pub fn try_from_name(name: &str) -> Result<&'static Self, LookupError> {
+        Self::from_name(name).ok_or_else(|| LookupError::NotFound {
+            input: name.to_string(),
+            suggestions: suggestions(name, NAMES.keys().copied()),
+        })
+    }
+    /// Get a list of countries from a capital like
+    /// [`from_capital`](#method.from_capital), failing if the `from_capitals`
+    /// feature is disabled
+    pub fn try_from_capital(capital: &str) -> Result<&'static [Self], LookupError> {
+        #[cfg(feature = "from_capitals")]
+        return Self::from_capital(capital).ok_or_else(|| LookupError::NotFound {
+            input: capital.to_string(),
+            suggestions: Vec::new(),
+        });
+        #[cfg(not(feature = "from_capitals"))]
+        Err(LookupError::FeatureDisabled {
+            input: capital.to_string(),
+            feature: "from_capitals",
+        })
+    }
+    /// Get the country from its ISO 3166-1 alpha_2 code, telling malformed codes
+    /// apart from unassigned ones, and failing if the `from_alpha_2` feature is
+    /// disabled
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    /// use iso_rs::LookupError;
+    ///
+    /// let error = Country::try_from_alpha_2("I1").unwrap_err();
+    /// assert!(matches!(error, LookupError::Malformed { .. }));
+    /// ```
+    pub fn try_from_alpha_2(alpha_2: &str) -> Result<&'static Self, LookupError> {
+        check_code(alpha_2, 2, MatchKind::Alpha2)?;
+        #[cfg(feature = "from_alpha_2")]
+        return Self::from_alpha_2(alpha_2).ok_or_else(|| LookupError::Unassigned {
+            input: alpha_2.to_string(),
+            kind: MatchKind::Alpha2,
+        });
+        #[cfg(not(feature = "from_alpha_2"))]
+        Err(LookupError::FeatureDisabled {
+            input: alpha_2.to_string(),
+            feature: "from_alpha_2",
+        })
+    }
+    /// Get the country from its ISO 3166-1 alpha_3 code, telling malformed codes
+    /// apart from unassigned ones, and failing if the `from_alpha_3` feature is
+    /// disabled
+    pub fn try_from_alpha_3(alpha_3: &str) -> Result<&'static Self, LookupError> {
+        check_code(alpha_3, 3, MatchKind::Alpha3)?;
+        #[cfg(feature = "from_alpha_3")]
+        return Self::from_alpha_3(alpha_3).ok_or_else(|| LookupError::Unassigned {
+            input: alpha_3.to_string(),
+            kind: MatchKind::Alpha3,
+        });
+        #[cfg(not(feature = "from_alpha_3"))]
+        Err(LookupError::FeatureDisabled {
+            input: alpha_3.to_string(),
+            feature: "from_alpha_3",
+        })
+    }
+    /// Get the country from its IOC code, telling malformed codes apart from
+    /// unassigned ones, and failing if the `from_ioc_codes` feature is disabled
+    pub fn try_from_ioc_code(ioc_code: &str) -> Result<&'static Self, LookupError> {
+        check_code(ioc_code, 3, MatchKind::Ioc)?;
+        #[cfg(feature = "from_ioc_codes")]
+        return Self::from_ioc_code(ioc_code).ok_or_else(|| LookupError::Unassigned {
+            input: ioc_code.to_string(),
+            kind: MatchKind::Ioc,
+        });
+        #[cfg(not(feature = "from_ioc_codes"))]
+        Err(LookupError::FeatureDisabled {
+            input: ioc_code.to_string(),
+            feature: "from_ioc_codes",
+        })
+    }
+}
+
+impl FromStr for &'static Country {
+    type Err = LookupError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Country::try_lookup(input).map(|lookup| lookup.country)
+    }
+}
+
+impl TryFrom<&str> for &'static Country {
+    type Error = LookupError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
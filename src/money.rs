@@ -0,0 +1,316 @@
+use crate::{Currency, MoneyError};
+use std::fmt;
+use std::str::FromStr;
+
+/// An amount of money, counted in the minor unit of its currency so that
+/// arithmetic is exact, eg. 1250 for 12.50 EUR.
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::{Money, prelude::*};
+///
+/// let euro = Currency::from_code("EUR").unwrap();
+/// let price = Money::from_decimal("12.50", euro).unwrap();
+/// let total = price.checked_mul(3).unwrap();
+/// assert_eq!(total.to_string(), "37.50 EUR");
+///
+/// let yen = Money::new(500, Currency::from_code("JPY").unwrap()).unwrap();
+/// assert!(total.checked_add(yen).is_err());
+/// ```
+///
+/// Amounts are equal when they have the same count of minor units and their
+/// currencies the same code, the same test as the arithmetic uses.
+#[derive(Debug, Copy, Clone)]
+pub struct Money {
+    /// Amount in the currency's minor unit, eg. 1250 for 12.50 EUR
+    pub amount: i64,
+    /// Currency of the amount, one with a minor unit
+    pub currency: &'static Currency,
+}
+
+// Code of a currency for errors, "" for the few country currencies without one
+fn code(currency: &Currency) -> String {
+    currency.code.unwrap_or_default().to_string()
+}
+
+// Whether amounts in the currencies can be mixed, by code
+fn same_code(left: &Currency, right: &Currency) -> bool {
+    left.code == right.code
+}
+
+// Rounds half away from zero to a multiple of `step`, a positive number
+fn round_to(amount: i64, step: i64) -> Option<i64> {
+    let remainder = amount % step;
+    let down = amount - remainder;
+    if remainder.abs() * 2 < step {
+        Some(down)
+    } else {
+        down.checked_add(step * remainder.signum())
+    }
+}
+
+impl Money {
+    /// Make an amount of a currency from its count of minor units
+    pub fn new(amount: i64, currency: &'static Currency) -> Result<Self, MoneyError> {
+        if currency.minor_unit.is_none() {
+            return Err(MoneyError::NoMinorUnit(code(currency)));
+        }
+        Ok(Self { amount, currency })
+    }
+
+    /// Make an amount of a currency from a decimal number, with at most as many
+    /// decimals as the currency's minor unit
+    ///
+    /// "." is the decimal separator, and ",", spaces or "'" can group the digits
+    /// before it, in threes or in twos before the last three like the Indian
+    /// "1,23,456.00". More decimals than the minor unit is an error rather than
+    /// rounded, whatever the digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{Money, MoneyError, prelude::*};
+    ///
+    /// let euro = Currency::from_code("EUR").unwrap();
+    /// assert_eq!(Money::from_decimal("1,234.56", euro).unwrap().amount, 123456);
+    /// assert_eq!(
+    ///     Money::from_decimal("1.234", euro),
+    ///     Err(MoneyError::TooManyDecimals("1.234".to_string(), 2))
+    /// );
+    /// ```
+    pub fn from_decimal(amount: &str, currency: &'static Currency) -> Result<Self, MoneyError> {
+        let Some(exponent) = currency.minor_unit else {
+            return Err(MoneyError::NoMinorUnit(code(currency)));
+        };
+        let invalid = || MoneyError::InvalidAmount(amount.to_string());
+        let trimmed = amount.trim();
+        let (negative, digits) = match trimmed.strip_prefix(['-', '−']) {
+            Some(digits) => (true, digits.trim_start()),
+            None => (false, trimmed),
+        };
+        let digits: String = digits
+            .chars()
+            .filter(|char| !matches!(char, ' ' | '\'' | '\u{a0}' | '\u{202f}'))
+            .collect();
+        let (major, minor) = digits.split_once('.').unwrap_or((&digits, ""));
+        // Groups of three, or of two before the last like the Indian "1,23,456.00"
+        let groups: Vec<&str> = major.split(',').collect();
+        if let [_, middle @ .., last] = groups.as_slice()
+            && (last.len() != 3 || middle.iter().any(|group| !(2..=3).contains(&group.len())))
+        {
+            return Err(invalid());
+        }
+        let major = groups.concat();
+        let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if (major.is_empty() && minor.is_empty()) || !all_digits(&major) || !all_digits(minor) {
+            return Err(invalid());
+        }
+        if minor.len() > usize::from(exponent) {
+            return Err(MoneyError::TooManyDecimals(amount.to_string(), exponent));
+        }
+
+        // Pad the fraction to the minor unit
+        let mut units: i64 = 0;
+        let padding = usize::from(exponent) - minor.len();
+        for digit in major.bytes().chain(minor.bytes()).map(|digit| digit - b'0') {
+            units = units
+                .checked_mul(10)
+                .and_then(|units| units.checked_add(i64::from(digit)))
+                .ok_or(MoneyError::Overflow)?;
+        }
+        units = 10_i64
+            .checked_pow(padding as u32)
+            .and_then(|scale| units.checked_mul(scale))
+            .ok_or(MoneyError::Overflow)?;
+        Self::new(if negative { -units } else { units }, currency)
+    }
+
+    /// Parse an amount with its currency's code or symbol before or after it,
+    /// eg. "US$12.50", "12.50 €", "-3.20 GBP"
+    ///
+    /// A symbol must belong to a single currency, see
+    /// [`Currency::from_symbol`](struct.Currency.html#method.from_symbol), or be
+    /// prefixed with the start of the currency's code, like the "HK" in "HK$". See
+    /// [`from_decimal`](#method.from_decimal) for how the amount is read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Money;
+    ///
+    /// let dollars = Money::parse("US$12.50").unwrap();
+    /// assert_eq!(dollars.currency.code, Some("USD"));
+    /// assert_eq!(dollars.amount, 1250);
+    ///
+    /// let euros = Money::parse("12.50 €").unwrap();
+    /// assert_eq!(euros.to_string(), "12.50 EUR");
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, MoneyError> {
+        let trimmed = input.trim();
+        let (negative, rest) = match trimmed.strip_prefix(['-', '−']) {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, trimmed),
+        };
+        let invalid = || MoneyError::InvalidAmount(input.to_string());
+        let start = rest
+            .find(|char: char| char.is_ascii_digit())
+            .ok_or_else(invalid)?;
+        let end = rest
+            .rfind(|char: char| char.is_ascii_digit())
+            .ok_or_else(invalid)?
+            + 1;
+        let (prefix, amount, suffix) = (&rest[..start], &rest[start..end], &rest[end..]);
+        // The sign can also come between a leading symbol and the digits, "$-5"
+        let (prefix, negative) = match prefix.trim_end().strip_suffix(['-', '−']) {
+            Some(prefix) if !negative => (prefix, true),
+            _ => (prefix, negative),
+        };
+        let marker = match (prefix.trim(), suffix.trim()) {
+            ("", "") => return Err(MoneyError::UnknownCurrency(String::new())),
+            (marker, "") | ("", marker) => marker,
+            _ => return Err(invalid()),
+        };
+        let currency = currency_from_marker(marker)?;
+        let money = Self::from_decimal(amount, currency)?;
+        Ok(Self {
+            amount: if negative {
+                -money.amount
+            } else {
+                money.amount
+            },
+            ..money
+        })
+    }
+
+    /// Add an amount in the same currency
+    pub fn checked_add(self, other: Money) -> Result<Self, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self.amount.checked_add(other.amount);
+        Ok(Self {
+            amount: amount.ok_or(MoneyError::Overflow)?,
+            ..self
+        })
+    }
+
+    /// Subtract an amount in the same currency
+    pub fn checked_sub(self, other: Money) -> Result<Self, MoneyError> {
+        self.same_currency(&other)?;
+        let amount = self.amount.checked_sub(other.amount);
+        Ok(Self {
+            amount: amount.ok_or(MoneyError::Overflow)?,
+            ..self
+        })
+    }
+
+    /// Multiply by a whole number, eg. a quantity
+    pub fn checked_mul(self, factor: i64) -> Result<Self, MoneyError> {
+        let amount = self.amount.checked_mul(factor);
+        Ok(Self {
+            amount: amount.ok_or(MoneyError::Overflow)?,
+            ..self
+        })
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if !same_code(self.currency, other.currency) {
+            return Err(MoneyError::CurrencyMismatch(
+                code(self.currency),
+                code(other.currency),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Round half away from zero to the smallest amount paid in cash, see
+    /// [`Currency::cash_increment`](struct.Currency.html#method.cash_increment)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{Money, prelude::*};
+    ///
+    /// let franc = Currency::from_code("CHF").unwrap();
+    /// let price = Money::from_decimal("12.33", franc).unwrap();
+    /// assert_eq!(price.round_cash().unwrap().to_string(), "12.35 CHF");
+    /// ```
+    pub fn round_cash(self) -> Result<Self, MoneyError> {
+        let amount = round_to(self.amount, self.currency.cash_increment());
+        Ok(Self {
+            amount: amount.ok_or(MoneyError::Overflow)?,
+            ..self
+        })
+    }
+
+    /// The amount as a decimal number, with as many decimals as the minor unit,
+    /// eg. "12.50"
+    pub fn to_decimal(&self) -> String {
+        let exponent = usize::from(self.currency.minor_unit.unwrap_or_default());
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let digits = format!(
+            "{:0>width$}",
+            self.amount.unsigned_abs(),
+            width = exponent + 1
+        );
+        let (major, minor) = digits.split_at(digits.len() - exponent);
+        match minor {
+            "" => format!("{sign}{major}"),
+            _ => format!("{sign}{major}.{minor}"),
+        }
+    }
+}
+
+// A currency code, a symbol of one currency, or a symbol prefixed with the start of a code
+fn currency_from_marker(marker: &str) -> Result<&'static Currency, MoneyError> {
+    if let Some(currency) = Currency::from_code(marker) {
+        return Ok(currency);
+    }
+    let mut candidates = Currency::from_symbol(marker);
+    if candidates.is_empty() {
+        let letters = marker.find(|char: char| !char.is_ascii_uppercase());
+        if let Some(split) = letters.filter(|split| *split > 0) {
+            let (prefix, symbol) = marker.split_at(split);
+            candidates = Currency::from_symbol(symbol);
+            candidates
+                .retain(|currency| currency.code.is_some_and(|code| code.starts_with(prefix)));
+        }
+    }
+    match candidates.as_slice() {
+        [currency] => Ok(currency),
+        [] => Err(MoneyError::UnknownCurrency(marker.to_string())),
+        _ => Err(MoneyError::AmbiguousSymbol {
+            symbol: marker.to_string(),
+            candidates: candidates
+                .iter()
+                .filter_map(|currency| currency.code)
+                .collect(),
+        }),
+    }
+}
+
+impl PartialEq for Money {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount && same_code(self.currency, other.currency)
+    }
+}
+
+impl Eq for Money {}
+
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.to_decimal(),
+            self.currency.code.unwrap_or_default()
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{COUNTRIES, Country, Indicator, Region};
+use std::ops::RangeBounds;
+
+type Filter<'a> = Box<dyn Fn(&Country) -> bool + 'a>;
+
+/// A composable query over all countries, created with
+/// [`Country::query`](struct.Country.html#method.query).
+///
+/// Every filter narrows the result down further, so a country has to match all
+/// of them. Results come in alpha_2 order, like [`Country::iter`](struct.Country.html#method.iter).
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::prelude::*;
+///
+/// # #[cfg(all(feature = "languages", feature = "currencies", feature = "sovereignty"))] {
+/// let countries = Country::query()
+///     .region(Region::Europe)
+///     .speaks("fr")
+///     .uses_currency("EUR")
+///     .independent(true)
+///     .collect();
+/// assert!(countries.contains(&Country::from_name("France").unwrap()));
+/// assert!(!countries.contains(&Country::from_name("Switzerland").unwrap()));
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Query<'a> {
+    filters: Vec<Filter<'a>>,
+}
+
+impl<'a> Query<'a> {
+    /// Create a query that matches every country
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the countries matching a custom predicate
+    pub fn filter(mut self, filter: impl Fn(&Country) -> bool + 'a) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// Keep the country with this name
+    pub fn name(self, name: &'a str) -> Self {
+        self.filter(move |country| country.name == name)
+    }
+
+    /// Keep the countries with this capital
+    pub fn capital(self, capital: &'a str) -> Self {
+        self.filter(move |country| country.capital == Some(capital))
+    }
+
+    /// Keep the countries inside a region
+    pub fn region(self, region: Region) -> Self {
+        self.filter(move |country| country.region == Some(region.as_str()))
+    }
+
+    /// Keep the countries inside a subregion
+    pub fn subregion(self, subregion: &'a str) -> Self {
+        self.filter(move |country| country.subregion == Some(subregion))
+    }
+
+    /// Keep the country with this ISO 3166-1 alpha_2 code
+    pub fn alpha_2(self, alpha_2: &'a str) -> Self {
+        self.filter(move |country| country.alpha_2 == alpha_2)
+    }
+
+    /// Keep the country with this ISO 3166-1 alpha_3 code
+    pub fn alpha_3(self, alpha_3: &'a str) -> Self {
+        self.filter(move |country| country.alpha_3 == alpha_3)
+    }
+
+    /// Keep the country with this ISO 3166-1 numeric code, eg. "356"
+    #[cfg(feature = "numeric_codes")]
+    pub fn numeric_code(self, numeric_code: &'a str) -> Self {
+        self.filter(move |country| country.numeric_code == Some(numeric_code))
+    }
+
+    /// Keep the countries with this alternative spelling of their name, eg. "Bhārat"
+    #[cfg(feature = "alt_spellings")]
+    pub fn alt_spelling(self, alt_spelling: &'a str) -> Self {
+        self.filter(move |country| country.alt_spellings.contains(&alt_spelling))
+    }
+
+    /// Keep the country with this flag emoji
+    #[cfg(feature = "flags")]
+    pub fn flag_emoji(self, flag_emoji: &'a str) -> Self {
+        self.filter(move |country| country.flag_emoji == flag_emoji)
+    }
+
+    /// Keep the country with this SVG flag URL
+    #[cfg(feature = "flags")]
+    pub fn flag_svg(self, flag_svg: &'a str) -> Self {
+        self.filter(move |country| country.flag_svg == Some(flag_svg))
+    }
+
+    /// Keep the country with this PNG flag URL
+    #[cfg(feature = "flags")]
+    pub fn flag_png(self, flag_png: &'a str) -> Self {
+        self.filter(move |country| country.flag_png == Some(flag_png))
+    }
+
+    /// Keep the country with this IOC code
+    #[cfg(feature = "ioc_codes")]
+    pub fn ioc_code(self, ioc_code: &'a str) -> Self {
+        self.filter(move |country| country.ioc_code == Some(ioc_code))
+    }
+
+    /// Keep the countries using a timezone, by IANA identifier
+    #[cfg(feature = "timezones")]
+    pub fn timezone(self, iana_identifier: &'a str) -> Self {
+        self.filter(move |country| {
+            country
+                .timezones
+                .iter()
+                .any(|timezone| timezone.iana_identifier == iana_identifier)
+        })
+    }
+
+    /// Keep the countries using a currency, by ISO 4217 code
+    #[cfg(feature = "currencies")]
+    pub fn uses_currency(self, code: &'a str) -> Self {
+        self.filter(move |country| {
+            country
+                .currencies
+                .iter()
+                .any(|currency| currency.code == Some(code))
+        })
+    }
+
+    /// Keep the countries speaking a language, by ISO 639-1 or ISO 639-2 code
+    #[cfg(feature = "languages")]
+    pub fn speaks(self, code: &'a str) -> Self {
+        self.filter(move |country| {
+            country
+                .languages
+                .iter()
+                .any(|language| language.iso639_1 == Some(code) || language.iso639_2 == Some(code))
+        })
+    }
+
+    /// Keep the countries using a dialling code, with or without the leading
+    /// "+", eg. "91" or "+91"
+    #[cfg(feature = "call_codes")]
+    pub fn call_code(self, call_code: &'a str) -> Self {
+        let call_code = call_code.strip_prefix('+').unwrap_or(call_code);
+        self.filter(move |country| country.call_codes.contains(&call_code))
+    }
+
+    /// Keep either the independent countries or the dependent ones
+    #[cfg(feature = "sovereignty")]
+    pub fn independent(self, independent: bool) -> Self {
+        self.filter(move |country| country.is_independent() == independent)
+    }
+
+    /// Keep the dependent territories of a sovereign state, by alpha_2 code
+    #[cfg(feature = "sovereignty")]
+    pub fn sovereign(self, alpha_2: &'a str) -> Self {
+        self.filter(move |country| country.sovereign == Some(alpha_2))
+    }
+
+    /// Keep the countries whose indicator value falls within a range, skipping
+    /// the ones without a value
+    pub fn indicator(self, indicator: Indicator, range: impl RangeBounds<f64> + 'a) -> Self {
+        self.filter(move |country| {
+            country
+                .indicator(indicator)
+                .is_some_and(|value| range.contains(&value))
+        })
+    }
+
+    /// Get an iterator over the matching countries
+    pub fn iter(&self) -> impl Iterator<Item = &'static Country> + '_ {
+        COUNTRIES
+            .iter()
+            .filter(|country| self.filters.iter().all(|filter| filter(country)))
+    }
+
+    /// Collect the matching countries
+    pub fn collect(self) -> Vec<&'static Country> {
+        self.iter().collect()
+    }
+}
+
+impl<'a> IntoIterator for Query<'a> {
+    type Item = &'static Country;
+    type IntoIter = std::vec::IntoIter<&'static Country>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.collect().into_iter()
+    }
+}
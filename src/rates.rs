@@ -0,0 +1,231 @@
+use crate::{Currency, Money, MoneyError};
+use std::collections::HashMap;
+
+/// Exchange rates to convert [`Money`](struct.Money.html) between currencies.
+///
+/// Implementations only need to give the rates they have. Converting also uses
+/// the inverse of a rate, goes through the [`base`](#method.base) currency when
+/// there is no rate for a pair, and converts withdrawn currencies at their
+/// fixed rate to the currency that replaced them, eg. DEM to EUR at 1.95583.
+///
+/// Rates are `f64`. Converting multiplies the amount by the exact binary value
+/// of the rate in integer arithmetic, so amounts of any size, up to `i64::MAX`
+/// minor units, convert without going through a float; the only error is that
+/// of the rate itself.
+pub trait RateTable {
+    /// How many units of `to` one unit of `from` buys, if the table has it
+    fn rate(&self, from: &Currency, to: &Currency) -> Option<f64>;
+
+    /// Currency to go through when there is no rate between two others
+    fn base(&self) -> Option<&'static Currency> {
+        None
+    }
+
+    /// How many units of `to` one unit of `from` buys, found from the table's
+    /// rates, their inverses, the base currency and fixed historic rates
+    fn conversion_rate(&self, from: &Currency, to: &Currency) -> Option<f64> {
+        let (from, from_factor) = pegged(from);
+        let (to, to_factor) = pegged(to);
+        let rate = quoted(self, from, to).or_else(|| {
+            let base = self.base()?;
+            Some(quoted(self, from, base)? * quoted(self, base, to)?)
+        })?;
+        Some(from_factor * rate / to_factor)
+    }
+
+    /// Convert an amount to another currency, rounding half away from zero to
+    /// its minor unit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{MemoryRateTable, Money, RateTable, prelude::*};
+    ///
+    /// let currency = |code| Currency::from_code(code).unwrap();
+    /// let rates = MemoryRateTable::new()
+    ///     .with_base(currency("EUR"))
+    ///     .with_rate(currency("EUR"), currency("USD"), 1.10)?
+    ///     .with_rate(currency("EUR"), currency("JPY"), 160.0)?;
+    ///
+    /// let dollars = Money::parse("US$11.00").unwrap();
+    /// assert_eq!(rates.convert(dollars, currency("EUR")).unwrap().to_string(), "10.00 EUR");
+    /// assert_eq!(rates.convert(dollars, currency("JPY")).unwrap().to_string(), "1600 JPY");
+    ///
+    /// let marks = Money::parse("1955.83 DEM").unwrap();
+    /// assert_eq!(rates.convert(marks, currency("USD")).unwrap().to_string(), "1100.00 USD");
+    /// # Ok::<(), iso_rs::MoneyError>(())
+    /// ```
+    fn convert(&self, money: Money, to: &'static Currency) -> Result<Money, MoneyError> {
+        let from = money.currency;
+        let code = |currency: &Currency| currency.code.unwrap_or_default().to_string();
+        let exponent = |currency: &Currency| {
+            let minor_unit = currency.minor_unit;
+            minor_unit.ok_or_else(|| MoneyError::NoMinorUnit(code(currency)))
+        };
+        let (from_exponent, to_exponent) = (exponent(from)?, exponent(to)?);
+        if from.code == to.code {
+            return Money::new(money.amount, to);
+        }
+        let rate = self
+            .conversion_rate(from, to)
+            .ok_or_else(|| MoneyError::NoRate(code(from), code(to)))?;
+        let digits = i32::from(to_exponent) - i32::from(from_exponent);
+        let amount = match exact(money.amount, rate, digits) {
+            Some(amount) => amount,
+            // Only for rates too far from 1 for i128, where a float is as good
+            None => {
+                let amount = (money.amount as f64 * rate * 10_f64.powi(digits)).round();
+                // Beyond this, the cast to i64 would saturate rather than fail
+                if !amount.is_finite() || amount.abs() >= i64::MAX as f64 {
+                    return Err(MoneyError::Overflow);
+                }
+                i128::from(amount as i64)
+            }
+        };
+        let amount = i64::try_from(amount).map_err(|_| MoneyError::Overflow)?;
+        Money::new(amount, to)
+    }
+}
+
+// The amount times the rate times 10 to the power of digits, rounded half away
+// from zero, worked out exactly from the rate's mantissa and binary exponent.
+// None if that doesn't fit an i128.
+fn exact(amount: i64, rate: f64, digits: i32) -> Option<i128> {
+    if !rate.is_finite() || rate <= 0.0 {
+        return None;
+    }
+    // rate is mantissa * 2^exponent, with the mantissa made odd
+    let bits = rate.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased - 1075),
+    };
+    let zeros = mantissa.trailing_zeros();
+    let (mantissa, exponent) = (mantissa >> zeros, exponent + zeros as i32);
+
+    let power = |base: i128, exponent: i32| base.checked_pow(exponent.unsigned_abs());
+    let mut numerator = i128::from(amount) * i128::from(mantissa);
+    let mut denominator = 1_i128;
+    for (base, exponent) in [(10, digits), (2, exponent)] {
+        if exponent >= 0 {
+            numerator = numerator.checked_mul(power(base, exponent)?)?;
+        } else {
+            denominator = denominator.checked_mul(power(base, exponent)?)?;
+        }
+    }
+    let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+    if remainder.abs() >= denominator - remainder.abs() {
+        Some(quotient + numerator.signum())
+    } else {
+        Some(quotient)
+    }
+}
+
+// A rate given by the table either way round, ignoring any that can't be used
+fn quoted<T: RateTable + ?Sized>(table: &T, from: &Currency, to: &Currency) -> Option<f64> {
+    if from.code == to.code {
+        return Some(1.0);
+    }
+    let usable = |rate: &f64| rate.is_finite() && *rate > 0.0;
+    let direct = table.rate(from, to).filter(usable);
+    direct.or_else(|| table.rate(to, from).filter(usable).map(|rate| 1.0 / rate))
+}
+
+// The currency a withdrawn one was converted into at a fixed rate, following
+// replacements, with how many units of it one unit of the withdrawn one is worth
+fn pegged(currency: &Currency) -> (&Currency, f64) {
+    let mut pegged = (currency, 1.0);
+    while let (Some(replaced_by), Some(rate)) = (pegged.0.replaced_by, pegged.0.conversion_rate) {
+        let (Some(replacement), Ok(rate)) = (Currency::from_code(replaced_by), rate.parse::<f64>())
+        else {
+            break;
+        };
+        pegged = (replacement, pegged.1 / rate);
+    }
+    pegged
+}
+
+/// A [`RateTable`](trait.RateTable.html) of rates kept in memory, eg. loaded
+/// from a file of the day's rates.
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::{MemoryRateTable, RateTable, prelude::*};
+///
+/// let euro = Currency::from_code("EUR").unwrap();
+/// let pound = Currency::from_code("GBP").unwrap();
+/// let mut rates = MemoryRateTable::new();
+/// rates.insert(euro, pound, 0.85)?;
+/// assert_eq!(rates.rate(euro, pound), Some(0.85));
+/// assert!(rates.conversion_rate(pound, euro).is_some());
+/// assert!(rates.insert(euro, pound, f64::NAN).is_err());
+/// # Ok::<(), iso_rs::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryRateTable {
+    base: Option<&'static Currency>,
+    // By code, how many units of the second one unit of the first buys. Only
+    // positive and finite rates between currencies with a code are kept.
+    rates: HashMap<(&'static str, &'static str), f64>,
+}
+
+impl MemoryRateTable {
+    /// Start a table with no rates and no base currency
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Go through `base` when there is no rate between two currencies
+    pub fn with_base(mut self, base: &'static Currency) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Add how many units of `to` one unit of `from` buys, see [`insert`](#method.insert)
+    pub fn with_rate(
+        mut self,
+        from: &'static Currency,
+        to: &'static Currency,
+        rate: f64,
+    ) -> Result<Self, MoneyError> {
+        self.insert(from, to, rate)?;
+        Ok(self)
+    }
+
+    /// Add how many units of `to` one unit of `from` buys, replacing any rate
+    /// for the pair
+    ///
+    /// Fails for a rate that isn't positive and finite, or for a currency
+    /// without a code, as rates are kept by code.
+    pub fn insert(
+        &mut self,
+        from: &'static Currency,
+        to: &'static Currency,
+        rate: f64,
+    ) -> Result<(), MoneyError> {
+        let code = |currency: &'static Currency| {
+            let name = currency.name.unwrap_or_default().to_string();
+            currency.code.ok_or(MoneyError::NoCode(name))
+        };
+        let (from, to) = (code(from)?, code(to)?);
+        if !rate.is_finite() || rate <= 0.0 {
+            let (from, to, rate) = (from.to_string(), to.to_string(), rate.to_string());
+            return Err(MoneyError::InvalidRate { from, to, rate });
+        }
+        self.rates.insert((from, to), rate);
+        Ok(())
+    }
+}
+
+impl RateTable for MemoryRateTable {
+    fn rate(&self, from: &Currency, to: &Currency) -> Option<f64> {
+        self.rates.get(&(from.code?, to.code?)).copied()
+    }
+
+    fn base(&self) -> Option<&'static Currency> {
+        self.base
+    }
+}
@@ -0,0 +1,377 @@
+use crate::country_data::{CountryData, CurrencyData, LanguageData};
+use crate::lookup::Keys;
+use crate::{Country, Currency, Language};
+use std::collections::HashMap;
+
+/// Owned countries along with the maps the static ones are generated into, for
+/// the lookups of a [`Registry`](struct.Registry.html) or a runtime `Dataset`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Records {
+    // Sorted by alpha_2 code, like COUNTRIES, and in their original order
+    // within a repeated code
+    pub(crate) countries: Vec<CountryData>,
+    // Copies of the countries by capital, region and subregion, like the
+    // CAPITALS, REGIONS and SUBREGIONS maps
+    pub(crate) capitals: HashMap<String, Vec<CountryData>>,
+    pub(crate) regions: HashMap<String, Vec<CountryData>>,
+    pub(crate) subregions: HashMap<String, Vec<CountryData>>,
+}
+
+impl Records {
+    pub(crate) fn new(countries: Vec<CountryData>) -> Self {
+        let mut records = Self {
+            countries,
+            ..Self::default()
+        };
+        records.index();
+        records
+    }
+
+    // Sort the countries and rebuild the maps, after the countries changed
+    pub(crate) fn index(&mut self) {
+        self.countries.sort_by(|a, b| a.alpha_2.cmp(&b.alpha_2));
+        let group = |key: fn(&CountryData) -> Option<&String>| {
+            let mut map: HashMap<String, Vec<CountryData>> = HashMap::new();
+            for country in &self.countries {
+                if let Some(key) = key(country) {
+                    map.entry(key.clone()).or_default().push(country.clone());
+                }
+            }
+            map
+        };
+        let capitals = group(|country| country.capital.as_ref());
+        let regions = group(|country| country.region.as_ref());
+        let subregions = group(|country| country.subregion.as_ref());
+        self.capitals = capitals;
+        self.regions = regions;
+        self.subregions = subregions;
+    }
+
+    // Where the country with the alpha_2 code is, or would go
+    pub(crate) fn position(&self, alpha_2: &str) -> Result<usize, usize> {
+        let position = self
+            .countries
+            .partition_point(|country| country.alpha_2.as_str() < alpha_2);
+        match self.countries.get(position) {
+            Some(country) if country.alpha_2 == alpha_2 => Ok(position),
+            _ => Err(position),
+        }
+    }
+}
+
+// The lookups of `Country`, for a type keeping its countries in a `records`
+// field of type `Records`
+macro_rules! country_lookups {
+    () => {
+        /// Get an iterator over all the countries, sorted by alpha_2 code
+        pub fn iter(&self) -> std::slice::Iter<'_, $crate::CountryData> {
+            self.records.countries.iter()
+        }
+
+        /// Get the country from its name
+        pub fn from_name(&self, name: &str) -> Option<&$crate::CountryData> {
+            self.iter().find(|country| country.name == name)
+        }
+
+        /// Get a list of countries from a capital
+        pub fn from_capital(&self, capital: &str) -> Option<&[$crate::CountryData]> {
+            self.records.capitals.get(capital).map(Vec::as_slice)
+        }
+
+        /// Get a list of countries from a region
+        pub fn from_region(&self, region: &str) -> Option<&[$crate::CountryData]> {
+            self.records.regions.get(region).map(Vec::as_slice)
+        }
+
+        /// Get a list of countries from a subregion
+        pub fn from_subregion(&self, subregion: &str) -> Option<&[$crate::CountryData]> {
+            self.records.subregions.get(subregion).map(Vec::as_slice)
+        }
+
+        /// Get the country from its ISO 3166-1 alpha_2 code, the first one if
+        /// the data repeats it
+        pub fn from_alpha_2(&self, alpha_2: &str) -> Option<&$crate::CountryData> {
+            let position = self.records.position(alpha_2).ok()?;
+            Some(&self.records.countries[position])
+        }
+
+        /// Get the country from its ISO 3166-1 alpha_3 code
+        pub fn from_alpha_3(&self, alpha_3: &str) -> Option<&$crate::CountryData> {
+            self.iter().find(|country| country.alpha_3 == alpha_3)
+        }
+
+        /// Get the country from its ISO 3166-1 numeric code, eg. "356"
+        pub fn from_numeric_code(&self, numeric_code: &str) -> Option<&$crate::CountryData> {
+            self.iter()
+                .find(|country| country.numeric_code.as_deref() == Some(numeric_code))
+        }
+
+        /// Get the country from its IOC (Olympic) code
+        pub fn from_ioc_code(&self, ioc_code: &str) -> Option<&$crate::CountryData> {
+            self.iter()
+                .find(|country| country.ioc_code.as_deref() == Some(ioc_code))
+        }
+
+        /// Get the country from its flag emoji
+        pub fn from_flag_emoji(&self, flag_emoji: &str) -> Option<&$crate::CountryData> {
+            self.iter().find(|country| country.flag_emoji == flag_emoji)
+        }
+
+        /// Get an iterator over the sovereign states, leaving out territories
+        pub fn independent_countries(&self) -> impl Iterator<Item = &$crate::CountryData> {
+            self.iter().filter(|country| country.independent)
+        }
+
+        /// Get the sovereign state a territory belongs to, `None` for
+        /// independent countries
+        pub fn sovereign(&self, country: &$crate::CountryData) -> Option<&$crate::CountryData> {
+            self.from_alpha_2(country.sovereign.as_deref()?)
+        }
+
+        /// Get the territories that belong to a sovereign state, sorted by
+        /// alpha_2 code
+        pub fn territories(&self, country: &$crate::CountryData) -> Vec<&$crate::CountryData> {
+            self.iter()
+                .filter(|territory| territory.sovereign.as_deref() == Some(&country.alpha_2))
+                .collect()
+        }
+
+        /// Find a country from any identifier like
+        /// [`Country::lookup`](struct.Country.html#method.lookup)
+        pub fn lookup(&self, input: &str) -> Option<$crate::Lookup<&$crate::CountryData>> {
+            $crate::lookup::first(
+                self.iter(),
+                &<$crate::CountryData as $crate::lookup::Keys>::is_alt_spelling,
+                input,
+            )
+        }
+
+        /// Find a country from any identifier like
+        /// [`Country::try_lookup`](struct.Country.html#method.try_lookup)
+        pub fn try_lookup(
+            &self,
+            input: &str,
+        ) -> Result<$crate::Lookup<&$crate::CountryData>, $crate::LookupError<&$crate::CountryData>>
+        {
+            $crate::lookup::only(
+                self.iter(),
+                &<$crate::CountryData as $crate::lookup::Keys>::is_alt_spelling,
+                self.iter().map(|country| country.name.as_str()),
+                input,
+            )
+        }
+
+        /// Get the country from its name, suggesting close names if there is none
+        pub fn try_from_name(
+            &self,
+            name: &str,
+        ) -> Result<&$crate::CountryData, $crate::LookupError<&$crate::CountryData>> {
+            self.from_name(name)
+                .ok_or_else(|| $crate::LookupError::NotFound {
+                    input: name.to_string(),
+                    suggestions: $crate::lookup::suggestions(
+                        name,
+                        self.iter().map(|country| country.name.as_str()),
+                    ),
+                })
+        }
+
+        /// Get a list of countries from a capital, saying if there are none
+        pub fn try_from_capital(
+            &self,
+            capital: &str,
+        ) -> Result<&[$crate::CountryData], $crate::LookupError<&$crate::CountryData>> {
+            self.from_capital(capital)
+                .ok_or_else(|| $crate::LookupError::NotFound {
+                    input: capital.to_string(),
+                    suggestions: Vec::new(),
+                })
+        }
+
+        /// Get the country from its ISO 3166-1 alpha_2 code, telling malformed
+        /// codes apart from unassigned ones
+        pub fn try_from_alpha_2(
+            &self,
+            alpha_2: &str,
+        ) -> Result<&$crate::CountryData, $crate::LookupError<&$crate::CountryData>> {
+            self.try_code(alpha_2, 2, $crate::MatchKind::Alpha2, Self::from_alpha_2)
+        }
+
+        /// Get the country from its ISO 3166-1 alpha_3 code, telling malformed
+        /// codes apart from unassigned ones
+        pub fn try_from_alpha_3(
+            &self,
+            alpha_3: &str,
+        ) -> Result<&$crate::CountryData, $crate::LookupError<&$crate::CountryData>> {
+            self.try_code(alpha_3, 3, $crate::MatchKind::Alpha3, Self::from_alpha_3)
+        }
+
+        /// Get the country from its IOC code, telling malformed codes apart from
+        /// unassigned ones
+        pub fn try_from_ioc_code(
+            &self,
+            ioc_code: &str,
+        ) -> Result<&$crate::CountryData, $crate::LookupError<&$crate::CountryData>> {
+            self.try_code(ioc_code, 3, $crate::MatchKind::Ioc, Self::from_ioc_code)
+        }
+
+        fn try_code<'a>(
+            &'a self,
+            code: &str,
+            len: usize,
+            kind: $crate::MatchKind,
+            find: fn(&'a Self, &str) -> Option<&'a $crate::CountryData>,
+        ) -> Result<&'a $crate::CountryData, $crate::LookupError<&'a $crate::CountryData>> {
+            $crate::lookup::check_code(code, len, kind)?;
+            find(self, code).ok_or_else(|| $crate::LookupError::Unassigned {
+                input: code.to_string(),
+                kind,
+            })
+        }
+    };
+}
+
+pub(crate) use country_lookups;
+
+impl Keys for CountryData {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn capital(&self) -> Option<&str> {
+        self.capital.as_deref()
+    }
+    fn alpha_2(&self) -> &str {
+        &self.alpha_2
+    }
+    fn alpha_3(&self) -> &str {
+        &self.alpha_3
+    }
+    fn numeric_code(&self) -> Option<&str> {
+        self.numeric_code.as_deref()
+    }
+    fn ioc_code(&self) -> Option<&str> {
+        self.ioc_code.as_deref()
+    }
+    fn is_alt_spelling(&self, input: &str) -> bool {
+        self.alt_spellings
+            .iter()
+            .any(|spelling| spelling.to_lowercase() == input)
+    }
+}
+
+fn owned(value: Option<&str>) -> Option<String> {
+    value.map(str::to_string)
+}
+
+impl From<&Currency> for CurrencyData {
+    fn from(currency: &Currency) -> Self {
+        Self {
+            code: owned(currency.code),
+            name: owned(currency.name),
+            symbol: owned(currency.symbol),
+            numeric_code: owned(currency.numeric_code),
+            minor_unit: currency.minor_unit,
+            kind: currency.kind,
+            introduced: owned(currency.introduced),
+            withdrawn: owned(currency.withdrawn),
+            replaced_by: owned(currency.replaced_by),
+            conversion_rate: owned(currency.conversion_rate),
+            cash_increment: currency.cash_increment,
+            narrow_symbol: owned(currency.narrow_symbol),
+        }
+    }
+}
+
+impl From<&Language> for LanguageData {
+    fn from(language: &Language) -> Self {
+        Self {
+            iso639_1: owned(language.iso639_1),
+            iso639_2: owned(language.iso639_2),
+            name: owned(language.name),
+            native_name: owned(language.native_name),
+            iso639_2b: owned(language.iso639_2b),
+            iso639_3: owned(language.iso639_3),
+            scope: language.scope,
+            language_type: language.language_type,
+            macrolanguage: owned(language.macrolanguage),
+            script: owned(language.script),
+        }
+    }
+}
+
+// Fields the crate was built without are left empty
+impl From<&Country> for CountryData {
+    fn from(country: &Country) -> Self {
+        Self {
+            name: country.name.to_string(),
+            capital: owned(country.capital),
+            region: owned(country.region),
+            subregion: owned(country.subregion),
+            alpha_2: country.alpha_2.to_string(),
+            alpha_3: country.alpha_3.to_string(),
+            #[cfg(feature = "timezones")]
+            timezones: country
+                .timezones
+                .iter()
+                .map(|timezone| crate::country_data::TimezoneData {
+                    iana_identifier: timezone.iana_identifier.to_string(),
+                })
+                .collect(),
+            #[cfg(not(feature = "timezones"))]
+            timezones: Vec::new(),
+            #[cfg(feature = "currencies")]
+            currencies: country.currencies.iter().map(CurrencyData::from).collect(),
+            #[cfg(not(feature = "currencies"))]
+            currencies: Vec::new(),
+            #[cfg(feature = "languages")]
+            languages: country.languages.iter().map(LanguageData::from).collect(),
+            #[cfg(not(feature = "languages"))]
+            languages: Vec::new(),
+            #[cfg(feature = "call_codes")]
+            call_codes: country
+                .call_codes
+                .iter()
+                .map(|code| code.to_string())
+                .collect(),
+            #[cfg(not(feature = "call_codes"))]
+            call_codes: Vec::new(),
+            #[cfg(feature = "sovereignty")]
+            independent: country.independent,
+            #[cfg(not(feature = "sovereignty"))]
+            independent: false,
+            #[cfg(feature = "sovereignty")]
+            sovereign: owned(country.sovereign),
+            #[cfg(not(feature = "sovereignty"))]
+            sovereign: None,
+            flag_emoji: crate::country_data::flag_emoji(country.alpha_2),
+            #[cfg(feature = "flags")]
+            flag_svg: owned(country.flag_svg),
+            #[cfg(not(feature = "flags"))]
+            flag_svg: None,
+            #[cfg(feature = "flags")]
+            flag_png: owned(country.flag_png),
+            #[cfg(not(feature = "flags"))]
+            flag_png: None,
+            #[cfg(feature = "ioc_codes")]
+            ioc_code: owned(country.ioc_code),
+            #[cfg(not(feature = "ioc_codes"))]
+            ioc_code: None,
+            #[cfg(feature = "gini")]
+            gini: country.gini,
+            #[cfg(not(feature = "gini"))]
+            gini: None,
+            #[cfg(feature = "numeric_codes")]
+            numeric_code: owned(country.numeric_code),
+            #[cfg(not(feature = "numeric_codes"))]
+            numeric_code: None,
+            #[cfg(feature = "alt_spellings")]
+            alt_spellings: country
+                .alt_spellings
+                .iter()
+                .map(|spelling| spelling.to_string())
+                .collect(),
+            #[cfg(not(feature = "alt_spellings"))]
+            alt_spellings: Vec::new(),
+        }
+    }
+}
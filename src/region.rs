@@ -0,0 +1,70 @@
+use std::fmt;
+
+/// A region a [`Country`](struct.Country.html) can be in, as found in `Country::region`.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Region {
+    /// "Africa"
+    Africa,
+    /// "Americas"
+    Americas,
+    /// "Antarctic", eg. Heard Island and McDonald Islands
+    Antarctic,
+    /// "Antarctic Ocean", eg. Bouvet Island
+    AntarcticOcean,
+    /// "Asia"
+    Asia,
+    /// "Europe"
+    Europe,
+    /// "Oceania"
+    Oceania,
+    /// "Polar", eg. Antarctica
+    Polar,
+}
+
+impl Region {
+    /// All the regions, in alphabetical order
+    pub const ALL: [Region; 8] = [
+        Region::Africa,
+        Region::Americas,
+        Region::Antarctic,
+        Region::AntarcticOcean,
+        Region::Asia,
+        Region::Europe,
+        Region::Oceania,
+        Region::Polar,
+    ];
+
+    /// Get the region from its name, as found in `Country::region`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Region;
+    ///
+    /// assert_eq!(Region::from_name("Europe"), Some(Region::Europe));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|region| region.as_str() == name)
+    }
+
+    /// Name of the region, as found in `Country::region`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Region::Africa => "Africa",
+            Region::Americas => "Americas",
+            Region::Antarctic => "Antarctic",
+            Region::AntarcticOcean => "Antarctic Ocean",
+            Region::Asia => "Asia",
+            Region::Europe => "Europe",
+            Region::Oceania => "Oceania",
+            Region::Polar => "Polar",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
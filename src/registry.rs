@@ -0,0 +1,173 @@
+use crate::country_data::{CountryData, flag_emoji};
+use crate::lookup::Keys;
+use crate::records::{Records, country_lookups};
+use crate::status::is_user_assigned;
+use crate::{COUNTRIES, OverrideError};
+
+/// The country tables with overrides applied at runtime, eg. to rename a
+/// disputed territory or hide a country for one tenant, without rebuilding.
+///
+/// A registry starts out with an owned copy of every country, as a
+/// [`CountryData`](struct.CountryData.html), and has the same lookups as
+/// [`Country`](struct.Country.html). Lookups only see the countries left after
+/// the overrides, with any new names and aliases. Overrides take owned strings,
+/// so they can come from configuration read at runtime.
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::Registry;
+///
+/// let registry = Registry::new()
+///     .rename("MK", "Macedonia")?
+///     .alias("MK", "FYROM")?
+///     .hide("AQ")?;
+///
+/// assert_eq!(registry.from_alpha_2("MK").unwrap().name, "Macedonia");
+/// assert_eq!(registry.lookup("fyrom").unwrap().country.alpha_2, "MK");
+/// assert!(registry.from_alpha_2("AQ").is_none());
+/// # Ok::<(), iso_rs::OverrideError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Registry {
+    records: Records,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Registry {
+    /// Start a registry with every country and no overrides
+    pub fn new() -> Self {
+        Self {
+            records: Records::new(COUNTRIES.iter().map(CountryData::from).collect()),
+        }
+    }
+
+    fn position(&self, alpha_2: &str) -> Result<usize, OverrideError> {
+        self.records
+            .position(alpha_2)
+            .map_err(|_| OverrideError::UnknownCountry(alpha_2.to_string()))
+    }
+
+    // Fail if a country other than the one at the position has the name, or
+    // has it as an alternative spelling, ignoring case like the lookups do
+    fn check_name(&self, position: usize, name: &str) -> Result<(), OverrideError> {
+        let lowercase = name.to_lowercase();
+        let taken = self
+            .records
+            .countries
+            .iter()
+            .enumerate()
+            .any(|(other, country)| {
+                other != position
+                    && (country.name.to_lowercase() == lowercase
+                        || country.is_alt_spelling(&lowercase))
+            });
+        match taken {
+            true => Err(OverrideError::NameInUse(name.to_string())),
+            false => Ok(()),
+        }
+    }
+
+    /// Give the country with the alpha_2 code a different name, which no other
+    /// country may have as its name or an alternative spelling
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Registry;
+    ///
+    /// // eg. from a tenant's configuration
+    /// let name = String::from("Türkiye");
+    /// let registry = Registry::new().rename("TR", name)?;
+    /// assert_eq!(registry.from_name("Türkiye").unwrap().alpha_3, "TUR");
+    /// # Ok::<(), iso_rs::OverrideError>(())
+    /// ```
+    pub fn rename(mut self, alpha_2: &str, name: impl Into<String>) -> Result<Self, OverrideError> {
+        let position = self.position(alpha_2)?;
+        let name = name.into();
+        self.check_name(position, &name)?;
+        self.records.countries[position].name = name;
+        self.records.index();
+        Ok(self)
+    }
+
+    /// Remove the country with the alpha_2 code from every lookup
+    pub fn hide(mut self, alpha_2: &str) -> Result<Self, OverrideError> {
+        let position = self.position(alpha_2)?;
+        self.records.countries.remove(position);
+        self.records.index();
+        Ok(self)
+    }
+
+    /// Let the country with the alpha_2 code be found by another name, as an
+    /// alternative spelling, which no other country may have
+    pub fn alias(mut self, alpha_2: &str, alias: impl Into<String>) -> Result<Self, OverrideError> {
+        let position = self.position(alpha_2)?;
+        let alias = alias.into();
+        self.check_name(position, &alias)?;
+        self.records.countries[position].alt_spellings.push(alias);
+        self.records.index();
+        Ok(self)
+    }
+
+    /// Add a country under a user-assigned code, one of AA, QM to QZ, XA to XZ
+    /// or ZZ, eg. for an entity the data doesn't have. Besides the codes, the
+    /// name and the flag emoji, every field is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Registry;
+    ///
+    /// let registry = Registry::new().assign("XS", "XSC", "Sark")?;
+    /// assert_eq!(registry.from_alpha_3("XSC").unwrap().name, "Sark");
+    /// # Ok::<(), iso_rs::OverrideError>(())
+    /// ```
+    pub fn assign(
+        mut self,
+        alpha_2: impl Into<String>,
+        alpha_3: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Result<Self, OverrideError> {
+        let (alpha_2, alpha_3) = (alpha_2.into(), alpha_3.into());
+        if !is_user_assigned(&alpha_2) {
+            return Err(OverrideError::NotUserAssigned(alpha_2));
+        }
+        // The alpha_3 ranges are the alpha_2 ones with any third letter
+        let (prefix, last) = alpha_3.split_at_checked(2).unwrap_or_default();
+        if !is_user_assigned(prefix) || !matches!(last.as_bytes(), [b'A'..=b'Z']) {
+            return Err(OverrideError::NotUserAssigned(alpha_3));
+        }
+        if self.from_alpha_3(&alpha_3).is_some() {
+            return Err(OverrideError::CodeInUse(alpha_3));
+        }
+        let Err(position) = self.records.position(&alpha_2) else {
+            return Err(OverrideError::CodeInUse(alpha_2));
+        };
+        let country = CountryData::builder()
+            .name(name.into())
+            .flag_emoji(flag_emoji(&alpha_2))
+            .alpha_2(alpha_2)
+            .alpha_3(alpha_3)
+            .build();
+        self.records.countries.insert(position, country);
+        self.records.index();
+        Ok(self)
+    }
+
+    country_lookups!();
+}
+
+impl<'a> IntoIterator for &'a Registry {
+    type Item = &'a CountryData;
+    type IntoIter = std::slice::Iter<'a, CountryData>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
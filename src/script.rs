@@ -0,0 +1,133 @@
+use crate::{Country, Language, SCRIPTS};
+
+/// Represents a writing system from ISO 15924.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Script {
+    /// ISO 15924 four-letter code, eg. "Latn"
+    pub alpha_4: &'static str,
+    /// ISO 15924 three-digit numeric code, eg. "215"
+    pub numeric_code: &'static str,
+    /// Name of the script in english, eg. "Latin"
+    pub name: &'static str,
+    /// Value of the Unicode `Script` property for the script, if Unicode encodes
+    /// it as one, eg. "Latin". Variants such as "Hans" and combinations such as
+    /// "Jpan" have none.
+    pub property_value_alias: Option<&'static str>,
+}
+
+impl Script {
+    /// Get an iterator over all the ISO 15924 scripts, sorted by alpha_4 code
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        SCRIPTS.iter()
+    }
+    /// Get the script from its ISO 15924 four-letter code, in any case
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Script;
+    ///
+    /// let cyrillic = Script::from_alpha_4("Cyrl").unwrap();
+    /// assert_eq!(cyrillic.numeric_code, "220");
+    /// assert_eq!(cyrillic.property_value_alias, Some("Cyrillic"));
+    /// assert_eq!(Script::from_alpha_4("CYRL"), Some(cyrillic));
+    /// ```
+    pub fn from_alpha_4(alpha_4: &str) -> Option<&'static Self> {
+        let mut code = alpha_4.to_ascii_lowercase();
+        code.get_mut(..1)?.make_ascii_uppercase();
+        let index = SCRIPTS
+            .binary_search_by_key(&code.as_str(), |script| script.alpha_4)
+            .ok()?;
+        Some(&SCRIPTS[index])
+    }
+    /// Get the script from its ISO 15924 numeric code, eg. "215"
+    pub fn from_numeric_code(numeric_code: &str) -> Option<&'static Self> {
+        Self::iter().find(|script| script.numeric_code == numeric_code)
+    }
+    /// Get the script from its english name, eg. "Devanagari (Nagari)"
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::iter().find(|script| script.name == name)
+    }
+    /// Get the script from the value of a Unicode `Script` property
+    ///
+    /// Values are matched loosely like Unicode does, ignoring case, spaces,
+    /// hyphens and underscores.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Script;
+    ///
+    /// let han = Script::from_property_value_alias("Han").unwrap();
+    /// assert_eq!(han.alpha_4, "Hani");
+    /// let canadian = Script::from_property_value_alias("canadian aboriginal").unwrap();
+    /// assert_eq!(canadian.alpha_4, "Cans");
+    /// ```
+    pub fn from_property_value_alias(alias: &str) -> Option<&'static Self> {
+        let loose = |value: &str| {
+            value
+                .chars()
+                .filter(|c| !matches!(c, ' ' | '-' | '_'))
+                .map(|c| c.to_ascii_lowercase())
+                .collect::<String>()
+        };
+        let alias = loose(alias);
+        Self::iter().find(|script| {
+            script
+                .property_value_alias
+                .is_some_and(|value| loose(value) == alias)
+        })
+    }
+}
+
+impl Language {
+    /// Get the script the language is usually written in
+    ///
+    /// Only set for languages with an ISO 639-1 code and some others that
+    /// countries use, after the likely subtags of the Unicode CLDR.
+    // The example looks the languages up by code, which needs iso639
+    #[cfg_attr(
+        feature = "iso639",
+        doc = r#"
+# Example
+
+```
+use iso_rs::prelude::*;
+
+let serbian = Language::from_iso639_1("sr").unwrap();
+assert_eq!(serbian.default_script().unwrap().alpha_4, "Cyrl");
+let chinese = Language::from_iso639_1("zh").unwrap();
+assert_eq!(chinese.default_script().unwrap().alpha_4, "Hans");
+```"#
+    )]
+    pub fn default_script(&self) -> Option<&'static Script> {
+        Script::from_alpha_4(self.script?)
+    }
+}
+
+impl Country {
+    /// Get the scripts of the country's languages, in the order of its languages
+    /// and without duplicates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let israel = Country::from_name("Israel").unwrap();
+    /// let scripts: Vec<_> = israel.scripts().iter().map(|script| script.alpha_4).collect();
+    /// assert_eq!(scripts, ["Hebr", "Arab"]);
+    /// // German, French, Italian and Romansh are all written in Latin script
+    /// assert_eq!(Country::from_name("Switzerland").unwrap().scripts().len(), 1);
+    /// ```
+    #[cfg(feature = "languages")]
+    pub fn scripts(&self) -> Vec<&'static Script> {
+        let mut scripts: Vec<&'static Script> = Vec::new();
+        for script in self.languages.iter().filter_map(Language::default_script) {
+            if !scripts.contains(&script) {
+                scripts.push(script);
+            }
+        }
+        scripts
+    }
+}
@@ -0,0 +1,191 @@
+use crate::COUNTRIES;
+#[cfg(feature = "historic")]
+use crate::HistoricCountry;
+
+/// How ISO 3166-1 treats an alpha_2 code, see [`classify_alpha_2`](fn.classify_alpha_2.html).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CodeStatus {
+    /// Assigned to a current country, eg. "GB"
+    OfficiallyAssigned,
+    /// Reserved on request of a national or international body for a
+    /// particular use, eg. "UK" or "EU"
+    ExceptionallyReserved,
+    /// Withdrawn from ISO 3166-1 and kept from being reassigned for a while,
+    /// eg. "YU"
+    TransitionallyReserved,
+    /// Used in other coding systems, such as international vehicle registration
+    /// codes, and so avoided in ISO 3166-1, eg. "RC"
+    IndeterminatelyReserved,
+    /// Left to users for their own codes: AA, QM to QZ, XA to XZ and ZZ, eg. "XK"
+    UserAssigned,
+    /// Not assigned or reserved
+    Unassigned,
+}
+
+/// An alpha_2 code reserved in ISO 3166-1 without being assigned to a country.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReservedCode {
+    /// The reserved code, eg. "UK"
+    pub alpha_2: &'static str,
+    /// What the code is reserved for, eg. "United Kingdom"
+    pub name: &'static str,
+    /// One of the reserved statuses
+    pub status: CodeStatus,
+    /// Why the code is reserved, where that is not obvious from the name
+    pub note: Option<&'static str>,
+}
+
+const fn reserved(
+    alpha_2: &'static str,
+    name: &'static str,
+    status: CodeStatus,
+    note: Option<&'static str>,
+) -> ReservedCode {
+    ReservedCode {
+        alpha_2,
+        name,
+        status,
+        note,
+    }
+}
+
+use CodeStatus::{ExceptionallyReserved, IndeterminatelyReserved, TransitionallyReserved};
+
+// Sorted by code, for binary search
+static RESERVED_CODES: &[ReservedCode] = &[
+    reserved("AC", "Ascension Island", ExceptionallyReserved, None),
+    reserved("AN", "Netherlands Antilles", TransitionallyReserved, None),
+    reserved("BU", "Burma", TransitionallyReserved, None),
+    reserved("CP", "Clipperton Island", ExceptionallyReserved, None),
+    reserved("CQ", "Island of Sark", ExceptionallyReserved, None),
+    reserved("CS", "Serbia and Montenegro", TransitionallyReserved, None),
+    reserved("DG", "Diego Garcia", ExceptionallyReserved, None),
+    reserved("DY", "Benin", IndeterminatelyReserved, None),
+    reserved("EA", "Ceuta, Melilla", ExceptionallyReserved, None),
+    reserved("EU", "European Union", ExceptionallyReserved, None),
+    reserved("EW", "Estonia", IndeterminatelyReserved, None),
+    reserved("EZ", "Eurozone", ExceptionallyReserved, None),
+    reserved("FL", "Liechtenstein", IndeterminatelyReserved, None),
+    reserved(
+        "FX",
+        "France, Metropolitan",
+        ExceptionallyReserved,
+        Some("Assigned until 1997"),
+    ),
+    reserved("IC", "Canary Islands", ExceptionallyReserved, None),
+    reserved("JA", "Jamaica", IndeterminatelyReserved, None),
+    reserved("LF", "Libya Fezzan", IndeterminatelyReserved, None),
+    reserved("NT", "Neutral Zone", TransitionallyReserved, None),
+    reserved("PI", "Philippines", IndeterminatelyReserved, None),
+    reserved("RA", "Argentina", IndeterminatelyReserved, None),
+    reserved("RB", "Bolivia, Botswana", IndeterminatelyReserved, None),
+    reserved("RC", "China", IndeterminatelyReserved, None),
+    reserved("RH", "Haiti", IndeterminatelyReserved, None),
+    reserved("RI", "Indonesia", IndeterminatelyReserved, None),
+    reserved("RL", "Lebanon", IndeterminatelyReserved, None),
+    reserved("RM", "Madagascar", IndeterminatelyReserved, None),
+    reserved("RN", "Niger", IndeterminatelyReserved, None),
+    reserved("RP", "Philippines", IndeterminatelyReserved, None),
+    reserved(
+        "SU",
+        "USSR",
+        ExceptionallyReserved,
+        Some("Assigned until 1992, still used by the .su domain"),
+    ),
+    reserved("TA", "Tristan da Cunha", ExceptionallyReserved, None),
+    reserved("TP", "East Timor", TransitionallyReserved, None),
+    reserved(
+        "UK",
+        "United Kingdom",
+        ExceptionallyReserved,
+        Some("Used by the .uk domain, the ISO 3166-1 code is GB"),
+    ),
+    reserved("UN", "United Nations", ExceptionallyReserved, None),
+    reserved("WG", "Grenada", IndeterminatelyReserved, None),
+    reserved("WL", "Saint Lucia", IndeterminatelyReserved, None),
+    reserved("WV", "Saint Vincent", IndeterminatelyReserved, None),
+    reserved("YU", "Yugoslavia", TransitionallyReserved, None),
+    reserved("YV", "Venezuela", IndeterminatelyReserved, None),
+    reserved("ZR", "Zaire", TransitionallyReserved, None),
+];
+
+// Whether ISO 3166-1 leaves an alpha_2 code to users: AA, QM to QZ, XA to XZ and ZZ
+pub(crate) fn is_user_assigned(alpha_2: &str) -> bool {
+    matches!(
+        alpha_2.as_bytes(),
+        [b'A', b'A'] | [b'Q', b'M'..=b'Z'] | [b'X', b'A'..=b'Z'] | [b'Z', b'Z']
+    )
+}
+
+impl ReservedCode {
+    /// Get an iterator over all the reserved codes, sorted by code
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        RESERVED_CODES.iter()
+    }
+    /// Get the reservation of an alpha_2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::{CodeStatus, ReservedCode};
+    ///
+    /// let uk = ReservedCode::from_alpha_2("UK").unwrap();
+    /// assert_eq!(uk.name, "United Kingdom");
+    /// assert_eq!(uk.status, CodeStatus::ExceptionallyReserved);
+    /// ```
+    pub fn from_alpha_2(alpha_2: &str) -> Option<&'static Self> {
+        let index = RESERVED_CODES
+            .binary_search_by_key(&alpha_2, |code| code.alpha_2)
+            .ok()?;
+        Some(&RESERVED_CODES[index])
+    }
+    /// Get the withdrawn countries that used a transitionally reserved code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::ReservedCode;
+    ///
+    /// let zaire = ReservedCode::from_alpha_2("ZR").unwrap();
+    /// assert_eq!(zaire.historic()[0].alpha_4, "ZRCD");
+    /// ```
+    #[cfg(feature = "historic")]
+    pub fn historic(&self) -> Vec<&'static HistoricCountry> {
+        HistoricCountry::from_alpha_2(self.alpha_2)
+    }
+}
+
+/// Classify an alpha_2 code by how ISO 3166-1 treats it, eg. to tell a typo
+/// from a code that is valid but not a country. Codes are matched exactly, so
+/// lowercase codes are unassigned.
+///
+/// Codes in the user-assigned ranges are classified as such even when the data
+/// has a country for them, like "XK" for Kosovo.
+///
+/// # Example
+///
+/// ```
+/// use iso_rs::{CodeStatus, classify_alpha_2};
+///
+/// assert_eq!(classify_alpha_2("GB"), CodeStatus::OfficiallyAssigned);
+/// assert_eq!(classify_alpha_2("UK"), CodeStatus::ExceptionallyReserved);
+/// assert_eq!(classify_alpha_2("YU"), CodeStatus::TransitionallyReserved);
+/// assert_eq!(classify_alpha_2("RC"), CodeStatus::IndeterminatelyReserved);
+/// assert_eq!(classify_alpha_2("XK"), CodeStatus::UserAssigned);
+/// assert_eq!(classify_alpha_2("OO"), CodeStatus::Unassigned);
+/// ```
+pub fn classify_alpha_2(alpha_2: &str) -> CodeStatus {
+    if is_user_assigned(alpha_2) {
+        CodeStatus::UserAssigned
+    } else if COUNTRIES
+        .binary_search_by_key(&alpha_2, |country| country.alpha_2)
+        .is_ok()
+    {
+        CodeStatus::OfficiallyAssigned
+    } else if let Some(reserved) = ReservedCode::from_alpha_2(alpha_2) {
+        reserved.status
+    } else {
+        CodeStatus::Unassigned
+    }
+}
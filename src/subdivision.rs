@@ -0,0 +1,125 @@
+use crate::{Country, SUBDIVISIONS};
+
+/// Represents an ISO 3166-2 subdivision of a country, like a state or province.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Subdivision {
+    /// ISO 3166-2 code, the country's alpha_2 code and up to three characters, eg. "US-CA"
+    pub code: &'static str,
+    /// Name of the subdivision, eg. "California"
+    pub name: &'static str,
+    /// Kind of subdivision in the country, eg. "State", "Province" or "Region"
+    pub category: &'static str,
+    /// Code of the subdivision this one is part of, eg. "GB-SCT" for "GB-ABD"
+    pub parent: Option<&'static str>,
+}
+
+impl Subdivision {
+    /// Get an iterator over all the subdivisions, sorted by code
+    pub fn iter() -> std::slice::Iter<'static, Self> {
+        SUBDIVISIONS.iter()
+    }
+    /// Get the subdivision from its ISO 3166-2 code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Subdivision;
+    ///
+    /// let california = Subdivision::from_code("US-CA").unwrap();
+    /// assert_eq!(california.name, "California");
+    /// assert_eq!(california.category, "State");
+    /// ```
+    pub fn from_code(code: &str) -> Option<&'static Self> {
+        let index = SUBDIVISIONS
+            .binary_search_by_key(&code, |subdivision| subdivision.code)
+            .ok()?;
+        Some(&SUBDIVISIONS[index])
+    }
+    /// Get the subdivisions with a name, in any country, sorted by code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Subdivision;
+    ///
+    /// let limburg = Subdivision::from_name("Limburg");
+    /// assert!(limburg.iter().any(|subdivision| subdivision.code == "NL-LI"));
+    /// ```
+    pub fn from_name(name: &str) -> Vec<&'static Self> {
+        SUBDIVISIONS
+            .iter()
+            .filter(|subdivision| subdivision.name == name)
+            .collect()
+    }
+    /// Get the alpha_2 code of the country the subdivision is in, eg. "US"
+    pub fn alpha_2(&self) -> &'static str {
+        self.code
+            .split_once('-')
+            .map_or(self.code, |(alpha_2, _)| alpha_2)
+    }
+    /// Get the country the subdivision is in
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Subdivision;
+    ///
+    /// let california = Subdivision::from_code("US-CA").unwrap();
+    /// assert_eq!(california.country().unwrap().alpha_3, "USA");
+    /// ```
+    pub fn country(&self) -> Option<&'static Country> {
+        let alpha_2 = self.alpha_2();
+        Country::iter().find(|country| country.alpha_2 == alpha_2)
+    }
+    /// Get the subdivision this one is part of, if any
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::Subdivision;
+    ///
+    /// let aberdeenshire = Subdivision::from_code("GB-ABD").unwrap();
+    /// assert_eq!(aberdeenshire.parent().unwrap().name, "Scotland");
+    /// ```
+    pub fn parent(&self) -> Option<&'static Self> {
+        Self::from_code(self.parent?)
+    }
+}
+
+impl Country {
+    /// Get the country's ISO 3166-2 subdivisions, sorted by code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let united_states = Country::from_name("United States of America").unwrap();
+    /// assert!(united_states.subdivisions().iter().any(|state| state.code == "US-CA"));
+    /// ```
+    pub fn subdivisions(&self) -> &'static [Subdivision] {
+        // Codes sort by country first, so the country's subdivisions are one run
+        let start =
+            SUBDIVISIONS.partition_point(|subdivision| subdivision.alpha_2() < self.alpha_2);
+        let len = SUBDIVISIONS[start..]
+            .iter()
+            .take_while(|subdivision| subdivision.alpha_2() == self.alpha_2)
+            .count();
+        &SUBDIVISIONS[start..start + len]
+    }
+    /// Get one of the country's subdivisions from its name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_rs::prelude::*;
+    ///
+    /// let canada = Country::from_name("Canada").unwrap();
+    /// assert_eq!(canada.subdivision_from_name("Ontario").unwrap().code, "CA-ON");
+    /// ```
+    pub fn subdivision_from_name(&self, name: &str) -> Option<&'static Subdivision> {
+        self.subdivisions()
+            .iter()
+            .find(|subdivision| subdivision.name == name)
+    }
+}
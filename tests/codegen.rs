@@ -0,0 +1,235 @@
+//! Runs the build script's code generation against strings that broke the old
+//! `format!` based generator, checking each one comes back out of the generated
+//! source exactly.
+
+// The build script modules are shared with it, and not all of them are used here.
+#![allow(dead_code)]
+
+#[path = "../build/codegen/mod.rs"]
+mod codegen;
+#[path = "../build/countries/country_data.rs"]
+mod country_data;
+#[path = "../src/currency_kind.rs"]
+mod currency_kind;
+#[path = "../build/historic/historic_data.rs"]
+mod historic_data;
+#[path = "../src/language_kind.rs"]
+mod language_kind;
+#[path = "../build/macros.rs"]
+mod macros;
+#[path = "../build/scripts/script_data.rs"]
+mod script_data;
+#[path = "../build/subdivisions/subdivision_data.rs"]
+mod subdivision_data;
+
+// Where codegen expects to find the data structs
+mod countries {
+    pub(crate) use super::country_data;
+}
+mod historic {
+    pub(crate) use super::historic_data;
+}
+mod scripts {
+    pub(crate) use super::script_data;
+}
+mod subdivisions {
+    pub(crate) use super::subdivision_data;
+}
+
+use codegen::{Fields, country_struct, currency_struct, language_struct, subdivision_struct};
+use country_data::{CountryData, CurrencyData, LanguageData, TimezoneData};
+use proc_macro2::{TokenStream, TokenTree};
+use std::str::FromStr;
+use subdivision_data::SubdivisionData;
+
+const ALL_FIELDS: Fields = Fields {
+    timezones: true,
+    currencies: true,
+    languages: true,
+    call_codes: true,
+    numeric_codes: true,
+    alt_spellings: true,
+    sovereignty: true,
+    flags: true,
+    ioc_codes: true,
+    gini: true,
+};
+
+const ADVERSARIAL: &[&str] = &[
+    "null",
+    "Some(None)",
+    "None",
+    "",
+    "\"quoted\"",
+    "back\\slash",
+    "line\nbreak",
+    "carriage\r\ttab",
+    "nul\0byte",
+    "escape\u{1b}[31m",
+    "delete\u{7f}",
+    "\u{202e}right to left",
+    "\u{feff}byte order mark",
+    "ਪੰਜਾਬੀ\u{a70}",
+    "🇮🇳",
+    "r#\"raw\"#",
+    "\"}, Country { name: \"injected\" } //",
+];
+
+// Every string literal in the generated source, in order, read back to its value
+fn string_literals(tokens: TokenStream, literals: &mut Vec<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => string_literals(group.stream(), literals),
+            TokenTree::Literal(literal) => {
+                if let Ok(string) = syn::parse_str::<syn::LitStr>(&literal.to_string()) {
+                    literals.push(string.value());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Render the tokens to source text and lex it again, like the build script's
+// output is when it gets included
+fn round_trip(tokens: TokenStream) -> Vec<String> {
+    let source = tokens.to_string();
+    let mut literals = Vec::new();
+    string_literals(TokenStream::from_str(&source).unwrap(), &mut literals);
+    literals
+}
+
+fn some(value: &str) -> Option<String> {
+    Some(value.to_string())
+}
+
+#[test]
+fn currency_strings_round_trip() {
+    for value in ADVERSARIAL {
+        let currency = CurrencyData {
+            code: some(value),
+            name: some(value),
+            symbol: some(value),
+            numeric_code: some(value),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(currency_struct(&currency)), [*value; 4]);
+        let currency = CurrencyData {
+            introduced: some(value),
+            withdrawn: some(value),
+            replaced_by: some(value),
+            conversion_rate: some(value),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(currency_struct(&currency)), [*value; 4]);
+    }
+}
+
+#[test]
+fn language_strings_round_trip() {
+    for value in ADVERSARIAL {
+        let language = LanguageData {
+            iso639_1: some(value),
+            iso639_2: some(value),
+            name: some(value),
+            native_name: some(value),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(language_struct(&language)), [*value; 4]);
+        let language = LanguageData {
+            iso639_2b: some(value),
+            iso639_3: some(value),
+            macrolanguage: some(value),
+            script: some(value),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(language_struct(&language)), [*value; 4]);
+    }
+}
+
+#[test]
+fn country_strings_round_trip() {
+    let strings = ADVERSARIAL.iter().map(|value| value.to_string());
+    let country = CountryData::builder()
+        .name(ADVERSARIAL.join(""))
+        .capital(some("null"))
+        .alpha_2(String::from("\n"))
+        .timezones(
+            strings
+                .clone()
+                .map(|iana_identifier| TimezoneData { iana_identifier })
+                .collect(),
+        )
+        .currencies(vec![CurrencyData::default()])
+        .languages(vec![LanguageData::default()])
+        .call_codes(strings.clone().collect())
+        .alt_spellings(strings.collect())
+        .build();
+    let mut expected = vec![ADVERSARIAL.join(""), String::from("null")];
+    expected.extend(["\n", ""].map(String::from));
+    expected.extend(ADVERSARIAL.iter().map(|value| value.to_string()));
+    expected.extend(ADVERSARIAL.iter().map(|value| value.to_string()));
+    expected.extend(ADVERSARIAL.iter().map(|value| value.to_string()));
+    expected.push(String::new());
+    assert_eq!(round_trip(country_struct(&country, &ALL_FIELDS)), expected);
+}
+
+#[test]
+fn subdivision_strings_round_trip() {
+    for value in ADVERSARIAL {
+        let subdivision = SubdivisionData {
+            code: value.to_string(),
+            name: value.to_string(),
+            category: value.to_string(),
+            parent: some(value),
+        };
+        assert_eq!(round_trip(subdivision_struct(&subdivision)), [*value; 4]);
+    }
+}
+
+#[test]
+fn missing_values_are_none() {
+    let source = currency_struct(&CurrencyData::default()).to_string();
+    assert_eq!(
+        source.split_whitespace().collect::<String>(),
+        "Currency{code:None,name:None,symbol:None,numeric_code:None,minor_unit:None,kind:None,introduced:None,withdrawn:None,replaced_by:None,conversion_rate:None,cash_increment:None,narrow_symbol:None,}"
+    );
+}
+
+#[test]
+fn whole_number_gini_is_a_float_literal() {
+    let country = CountryData::builder().gini(Some(29.0)).build();
+    let source = country_struct(&country, &ALL_FIELDS).to_string();
+    let gini = source.split("gini : Some (").nth(1).unwrap();
+    let gini = syn::parse_str::<syn::LitFloat>(gini.split(')').next().unwrap()).unwrap();
+    assert_eq!(gini.base10_parse::<f32>().unwrap(), 29.0);
+}
+
+#[test]
+fn turned_off_fields_are_left_out() {
+    let fields = Fields {
+        timezones: false,
+        currencies: false,
+        languages: false,
+        call_codes: false,
+        numeric_codes: false,
+        alt_spellings: false,
+        sovereignty: false,
+        flags: false,
+        ioc_codes: false,
+        gini: false,
+    };
+    let country = CountryData::builder()
+        .name(String::from("India"))
+        .alpha_2(String::from("IN"))
+        .alpha_3(String::from("IND"))
+        .currencies(vec![CurrencyData::default()])
+        .call_codes(vec![String::from("91")])
+        .gini(Some(35.7))
+        .build();
+    let source = country_struct(&country, &fields).to_string();
+    assert_eq!(
+        source.split_whitespace().collect::<String>(),
+        r#"Country{name:"India",capital:None,region:None,subregion:None,alpha_2:"IN",alpha_3:"IND",}"#
+    );
+}
@@ -0,0 +1,151 @@
+//! Runs the build script's data checks against records with one problem each,
+//! checking every rule flags its problem and lets a good record through.
+
+// The build script modules are shared with it, and not all of them are used here.
+#![allow(dead_code)]
+
+#[path = "../build/countries/country_data.rs"]
+mod country_data;
+#[path = "../src/currency_kind.rs"]
+mod currency_kind;
+#[path = "../src/language_kind.rs"]
+mod language_kind;
+#[path = "../src/region.rs"]
+mod region;
+#[path = "../build/validate.rs"]
+mod validate;
+
+// Where validate expects to find the data structs
+mod countries {
+    pub(crate) use super::country_data;
+}
+
+use country_data::{CountryData, CurrencyData, TimezoneData};
+use validate::{Diagnostics, NO_TIMEZONES, UniqueCodes, validate_country};
+
+fn india() -> CountryData {
+    CountryData::builder()
+        .name(String::from("India"))
+        .region(Some(String::from("Asia")))
+        .alpha_2(String::from("IN"))
+        .alpha_3(String::from("IND"))
+        .timezones(vec![TimezoneData {
+            iana_identifier: String::from("Asia/Kolkata"),
+        }])
+        .currencies(vec![CurrencyData {
+            code: Some(String::from("INR")),
+            ..Default::default()
+        }])
+        .build()
+}
+
+// Whether strict mode would fail the build on the record
+fn flagged(country: &CountryData) -> bool {
+    let mut diagnostics = Diagnostics::new(true);
+    validate_country(0, country, &mut diagnostics);
+    diagnostics.finish().is_err()
+}
+
+#[test]
+fn good_record_passes() {
+    assert!(!flagged(&india()));
+}
+
+#[test]
+fn warnings_only_fail_in_strict_mode() {
+    let mut diagnostics = Diagnostics::new(false);
+    diagnostics.issue(0, "India", "IN", "something is off");
+    assert!(diagnostics.finish().is_ok());
+    let mut diagnostics = Diagnostics::new(true);
+    diagnostics.issue(0, "India", "IN", "something is off");
+    assert!(diagnostics.finish().is_err());
+}
+
+#[test]
+fn malformed_codes_are_flagged() {
+    for alpha_2 in ["in", "IND", "I1", ""] {
+        let mut country = india();
+        country.alpha_2 = String::from(alpha_2);
+        assert!(flagged(&country), "{alpha_2:?}");
+    }
+    for alpha_3 in ["ind", "IN", "INDI"] {
+        let mut country = india();
+        country.alpha_3 = String::from(alpha_3);
+        assert!(flagged(&country), "{alpha_3:?}");
+    }
+}
+
+#[test]
+fn unknown_or_missing_region_is_flagged() {
+    let mut country = india();
+    country.region = Some(String::from("Atlantis"));
+    assert!(flagged(&country));
+    country.region = None;
+    assert!(flagged(&country));
+    country.region = Some(String::from("Antarctic Ocean"));
+    assert!(!flagged(&country));
+}
+
+#[test]
+fn timezones_are_checked() {
+    let mut country = india();
+    country.timezones[0].iana_identifier = String::from("Asia/Atlantis");
+    assert!(flagged(&country));
+    country.timezones.clear();
+    assert!(flagged(&country));
+    // Except for the countries zone.tab has no zone for
+    for alpha_2 in NO_TIMEZONES {
+        country.alpha_2 = alpha_2.to_string();
+        assert!(!flagged(&country), "{alpha_2}");
+    }
+}
+
+#[test]
+fn malformed_currency_codes_are_flagged() {
+    let mut country = india();
+    country.currencies[0].code = Some(String::from("IMP[G]"));
+    assert!(flagged(&country));
+    country.currencies[0].code = Some(String::from("inr"));
+    assert!(flagged(&country));
+    // A currency without a code is left alone
+    country.currencies[0].code = None;
+    assert!(!flagged(&country));
+}
+
+#[test]
+fn repeated_codes_are_an_error() {
+    let mut india = india();
+    india.ioc_code = Some(String::from("IND"));
+    let indonesia = CountryData::builder()
+        .name(String::from("Indonesia"))
+        .alpha_2(String::from("ID"))
+        .alpha_3(String::from("IDN"))
+        .ioc_code(Some(String::from("INA")))
+        .build();
+    // The error for adding the other country after India
+    let repeat = |other: &CountryData| {
+        let mut codes = UniqueCodes::new();
+        codes.add(&india).unwrap();
+        codes.add(other).err().map(|error| error.to_string())
+    };
+    assert_eq!(repeat(&indonesia), None);
+
+    let mut other = indonesia.clone();
+    other.alpha_2 = String::from("IN");
+    let message = "alpha_2 code IN is used by more than one country";
+    assert_eq!(repeat(&other).as_deref(), Some(message));
+    let mut other = indonesia.clone();
+    other.alpha_3 = String::from("IND");
+    let message = "alpha_3 code IND is used by more than one country";
+    assert_eq!(repeat(&other).as_deref(), Some(message));
+    let mut other = indonesia.clone();
+    other.ioc_code = Some(String::from("IND"));
+    let message = "IOC code IND is used by more than one country";
+    assert_eq!(repeat(&other).as_deref(), Some(message));
+    // Countries without an IOC code don't clash
+    other.ioc_code = None;
+    india.ioc_code = None;
+    let mut codes = UniqueCodes::new();
+    codes.add(&india).unwrap();
+    assert!(codes.add(&other).is_ok());
+}